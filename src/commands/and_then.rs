@@ -0,0 +1,84 @@
+use nu_plugin::SimplePluginCommand;
+use nu_protocol::{Category, Example, LabeledError, Signature, SyntaxShape, Type, Value};
+
+use crate::{
+    FpPlugin,
+    values::{FunctionValue, ResultValue},
+};
+
+#[derive(Clone)]
+pub struct AndThen;
+
+impl SimplePluginCommand for AndThen {
+    type Plugin = FpPlugin;
+
+    fn name(&self) -> &str {
+        "fp and-then"
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build(self.name())
+            .input_output_type(Type::Custom("result".into()), Type::Custom("result".into()))
+            .required(
+                "closure",
+                SyntaxShape::OneOf(vec![
+                    SyntaxShape::Closure(Some(vec![SyntaxShape::Any])),
+                    SyntaxShape::Any,
+                ]),
+                "The closure which may fail, called with the value of `ok`.",
+            )
+            .category(Category::Custom("functional".into()))
+    }
+
+    fn description(&self) -> &str {
+        "Chain a closure which may fail if the result is `ok`."
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"Call the closure on the value of `ok` like `fp try` does, so the result is `ok` with the result of the closure, or `err` if it fails. If the closure returns a result value, it becomes the result. `err` stays unchanged.
+
+The value is passed as both the parameter and the pipeline input of the closure."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["result", "bind", "flat-map", "chain"]
+    }
+
+    fn run(
+        &self,
+        plugin: &Self::Plugin,
+        engine: &nu_plugin::EngineInterface,
+        call: &nu_plugin::EvaluatedCall,
+        input: &Value,
+    ) -> Result<Value, LabeledError> {
+        let function = FunctionValue::from_callable(call.req(0)?)?;
+        match ResultValue::from_value(input)? {
+            ResultValue::Ok(value) => {
+                let outcome =
+                    function.call(plugin, engine, vec![value.clone()], Some(value.clone()));
+                match outcome {
+                    Ok(result) if ResultValue::try_from_value(&result).is_some() => Ok(result),
+                    outcome => {
+                        Ok(ResultValue::from_outcome(outcome, call.head).into_value(call.head))
+                    }
+                }
+            }
+            err @ ResultValue::Err(_) => Ok(err.clone().into_value(call.head)),
+        }
+    }
+
+    fn examples(&self) -> Vec<nu_protocol::Example<'_>> {
+        vec![
+            Example {
+                description: "Parse a number then divide by it",
+                example: "'2' | fp try { into int } | fp and-then {|x| 10 // $x} | fp unwrap",
+                result: Some(Value::test_int(5)),
+            },
+            Example {
+                description: "Second step failing",
+                example: "'0' | fp try { into int } | fp and-then {|x| 10 // $x} | fp is-ok",
+                result: Some(Value::test_bool(false)),
+            },
+        ]
+    }
+}
@@ -0,0 +1,88 @@
+use nu_plugin::SimplePluginCommand;
+use nu_protocol::{
+    Category, Example, LabeledError, Signature, Spanned, SyntaxShape, Type, Value, ast::CellPath,
+};
+
+use crate::{FpPlugin, type_pattern::TypePattern};
+
+#[derive(Clone)]
+pub struct AssertType;
+
+impl SimplePluginCommand for AssertType {
+    type Plugin = FpPlugin;
+
+    fn name(&self) -> &str {
+        "fp assert-type"
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build(self.name())
+            .input_output_type(Type::Any, Type::Any)
+            .required("type", SyntaxShape::String, "The expected type")
+            .category(Category::Filters)
+    }
+
+    fn description(&self) -> &str {
+        "Pass the input through if it is a specified type, otherwise raise an error."
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"Check the input with the same type pattern as `fp is`, returns the input unchanged if it matches.
+
+If the input does not match, an error is raised pointing at the first mismatched value with the expected and actual type."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["type", "type-check", "assert", "guard", "validate"]
+    }
+
+    fn run(
+        &self,
+        _plugin: &Self::Plugin,
+        _engine: &nu_plugin::EngineInterface,
+        call: &nu_plugin::EvaluatedCall,
+        input: &Value,
+    ) -> Result<Value, LabeledError> {
+        let text = call.req::<Spanned<String>>(0)?;
+        let pattern = TypePattern::parse_spanned(&text)?;
+
+        match pattern.mismatches(input, false).into_iter().next() {
+            None => Ok(input.clone()),
+            Some(m) => {
+                let location = if m.path.is_empty() {
+                    String::new()
+                } else {
+                    // Same as the `path` column of `fp is --explain`, without the `$.` prefix.
+                    let path = CellPath { members: m.path }.to_string();
+                    format!(" at `{}`", path.trim_start_matches("$."))
+                };
+                Err(LabeledError::new("Type mismatch")
+                    .with_label(
+                        format!("expected {}, found {}{location}", m.expected, m.actual),
+                        m.span,
+                    )
+                    .with_label(format!("input does not match `{}`", text.item), text.span))
+            }
+        }
+    }
+
+    fn examples(&self) -> Vec<nu_protocol::Example<'_>> {
+        vec![
+            Example {
+                description: "Pass through the input when it is int",
+                example: "1 | fp assert-type int",
+                result: Some(Value::test_int(1)),
+            },
+            Example {
+                description: "Guard a pipeline on the shape of a record",
+                example: r#"{name: "Alice", rank: 10} | fp assert-type 'record<name: string, rank>' | get name"#,
+                result: Some(Value::test_string("Alice")),
+            },
+            Example {
+                description: "Raise an error when a column has wrong type",
+                example: r#"[{name: "Alice", rank: "10"}] | fp assert-type 'table<rank: int>'"#,
+                result: None,
+            },
+        ]
+    }
+}
@@ -0,0 +1,80 @@
+use nu_plugin::SimplePluginCommand;
+use nu_protocol::{Category, Example, LabeledError, Signature, SyntaxShape, Type, Value};
+
+use crate::{
+    FpPlugin,
+    values::{FunctionValue, MaybeValue},
+};
+
+#[derive(Clone)]
+pub struct Bind;
+
+impl SimplePluginCommand for Bind {
+    type Plugin = FpPlugin;
+
+    fn name(&self) -> &str {
+        "fp bind"
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build(self.name())
+            .input_output_type(Type::Custom("maybe".into()), Type::Custom("maybe".into()))
+            .required(
+                "closure",
+                SyntaxShape::OneOf(vec![
+                    SyntaxShape::Closure(Some(vec![SyntaxShape::Any])),
+                    SyntaxShape::Any,
+                ]),
+                "The closure producing a maybe value from the present value.",
+            )
+            .category(Category::Custom("functional".into()))
+    }
+
+    fn description(&self) -> &str {
+        "Chain a closure returning a maybe value if the value is present."
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"Call the closure on the value of `fp some`, the closure must return `fp some` or `fp none`, which becomes the result. `fp none` stays `none`.
+
+The value is passed as both the parameter and the pipeline input of the closure."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["maybe", "option", "flat-map", "and-then", "chain"]
+    }
+
+    fn run(
+        &self,
+        plugin: &Self::Plugin,
+        engine: &nu_plugin::EngineInterface,
+        call: &nu_plugin::EvaluatedCall,
+        input: &Value,
+    ) -> Result<Value, LabeledError> {
+        let function = FunctionValue::from_callable(call.req(0)?)?;
+        match MaybeValue::from_value(input)? {
+            MaybeValue::Some(value) => {
+                let result =
+                    function.call(plugin, engine, vec![value.clone()], Some(value.clone()))?;
+                MaybeValue::from_value(&result)?;
+                Ok(result)
+            }
+            MaybeValue::None => Ok(MaybeValue::None.into_value(call.head)),
+        }
+    }
+
+    fn examples(&self) -> Vec<nu_protocol::Example<'_>> {
+        vec![
+            Example {
+                description: "Get a field which may be absent",
+                example: "fp some {a: 1} | fp bind {|r| if 'a' in $r { fp some $r.a } else { fp none }} | fp to-nullable",
+                result: Some(Value::test_int(1)),
+            },
+            Example {
+                description: "Closure returning an absent value",
+                example: "fp some {b: 1} | fp bind {|r| if 'a' in $r { fp some $r.a } else { fp none }} | fp unwrap-or 0",
+                result: Some(Value::test_int(0)),
+            },
+        ]
+    }
+}
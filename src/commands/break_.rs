@@ -0,0 +1,55 @@
+use nu_plugin::SimplePluginCommand;
+use nu_protocol::{Category, Example, Signature, SyntaxShape, Type, Value};
+
+use crate::{FpPlugin, values::BreakValue};
+
+#[derive(Clone)]
+pub struct Break;
+
+impl SimplePluginCommand for Break {
+    type Plugin = FpPlugin;
+
+    fn name(&self) -> &str {
+        "fp break"
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build(self.name())
+            .input_output_type(Type::Any, Type::Custom("break".into()))
+            .required("value", SyntaxShape::Any, "The value to stop with.")
+            .category(Category::Filters)
+    }
+
+    fn description(&self) -> &str {
+        "Stop an iteration early with a value."
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"Return this from the closure of iterating commands like `fp fold` to stop the iteration, the value becomes the result of the command."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["stop", "exit", "short-circuit"]
+    }
+
+    fn run(
+        &self,
+        _plugin: &Self::Plugin,
+        _engine: &nu_plugin::EngineInterface,
+        call: &nu_plugin::EvaluatedCall,
+        _input: &Value,
+    ) -> Result<Value, nu_protocol::LabeledError> {
+        Ok(BreakValue {
+            value: call.req(0)?,
+        }
+        .into_value(call.head))
+    }
+
+    fn examples(&self) -> Vec<nu_protocol::Example<'_>> {
+        vec![Example {
+            description: "Sum the elements until the sum is larger than 5",
+            example: "[1, 2, 4, 8] | fp fold 0 {|acc, x| if $acc > 5 { fp break $acc } else { $acc + $x } }",
+            result: Some(Value::test_int(7)),
+        }]
+    }
+}
@@ -0,0 +1,76 @@
+use nu_plugin::SimplePluginCommand;
+use nu_protocol::{Category, Example, LabeledError, Signature, SyntaxShape, Type, Value};
+
+use crate::{FpPlugin, values::FunctionValue};
+
+#[derive(Clone)]
+pub struct Call;
+
+impl SimplePluginCommand for Call {
+    type Plugin = FpPlugin;
+
+    fn name(&self) -> &str {
+        "fp call"
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build(self.name())
+            .input_output_type(Type::Any, Type::Any)
+            .required(
+                "function",
+                SyntaxShape::OneOf(vec![SyntaxShape::Closure(None), SyntaxShape::Any]),
+                "The function (or closure) to call.",
+            )
+            .rest(
+                "args",
+                SyntaxShape::Any,
+                "The arguments to call the function with.",
+            )
+            .category(Category::Custom("functional".into()))
+    }
+
+    fn description(&self) -> &str {
+        "Call a function built by `fp` commands."
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"Call a function (or closure) with the arguments, the input is passed as pipeline input.
+
+If no argument is given, the input is also passed as the only argument, so `5 | fp call $f` and `fp call $f 5` are the same."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["function", "closure", "invoke", "apply", "do"]
+    }
+
+    fn run(
+        &self,
+        plugin: &Self::Plugin,
+        engine: &nu_plugin::EngineInterface,
+        call: &nu_plugin::EvaluatedCall,
+        input: &Value,
+    ) -> Result<Value, LabeledError> {
+        let function = FunctionValue::from_callable(call.req(0)?)?;
+        let mut args = call.rest::<Value>(1)?;
+        if args.is_empty() {
+            args.push(input.clone());
+        }
+
+        function.call(plugin, engine, args, Some(input.clone()))
+    }
+
+    fn examples(&self) -> Vec<nu_protocol::Example<'_>> {
+        vec![
+            Example {
+                description: "Call a composed function with the input",
+                example: "let f = fp compose {|x| $x + 1} {|x| $x * 2}; 5 | fp call $f",
+                result: Some(Value::test_int(11)),
+            },
+            Example {
+                description: "Call a closure with arguments",
+                example: "fp call {|a, b| $a + $b} 1 2",
+                result: Some(Value::test_int(3)),
+            },
+        ]
+    }
+}
@@ -0,0 +1,161 @@
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    Category, Example, LabeledError, ListStream, PipelineData, Signature, SyntaxShape, Type, Value,
+    record,
+};
+
+use crate::{FpPlugin, values::FunctionValue};
+
+use super::condition::elements;
+
+#[derive(Clone)]
+pub struct ChunkBy;
+
+impl PluginCommand for ChunkBy {
+    type Plugin = FpPlugin;
+
+    fn name(&self) -> &str {
+        "fp chunk-by"
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        let chunks = Type::List(Box::new(Type::Record(Box::new([
+            ("key".into(), Type::Any),
+            ("items".into(), Type::List(Box::new(Type::Any))),
+        ]))));
+        Signature::build(self.name())
+            .input_output_types(vec![
+                (Type::List(Box::new(Type::Any)), chunks.clone()),
+                (Type::table(), chunks.clone()),
+                (Type::Range, chunks),
+            ])
+            .required(
+                "closure",
+                SyntaxShape::OneOf(vec![
+                    SyntaxShape::Closure(Some(vec![SyntaxShape::Any])),
+                    SyntaxShape::Any,
+                ]),
+                "The closure to get the key of an element.",
+            )
+            .category(Category::Filters)
+    }
+
+    fn description(&self) -> &str {
+        "Group consecutive elements with the same key."
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"Call the closure on each element to get its key, and group runs of consecutive elements with the same key into records of `{key, items}`.
+
+Unlike `group-by`, elements with the same key but not adjacent are in different groups. Each group is output as soon as the run ends, so it works on streams."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["group-adjacent", "group", "run", "consecutive", "split"]
+    }
+
+    fn run(
+        &self,
+        plugin: &Self::Plugin,
+        engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let function = FunctionValue::from_callable(call.req(0)?)?;
+        let signals = engine.signals().clone();
+        let plugin = plugin.clone();
+        let engine = engine.clone();
+        let head = call.head;
+
+        let mut elements = elements(input);
+        // The keyed first element of the next group (or the error getting it), already taken from
+        // input when ending the previous group.
+        let mut pending: Option<Result<(Value, Value), LabeledError>> = None;
+        let mut done = false;
+        // The stream checks signals between groups only, a group may take long to end.
+        let group_signals = signals.clone();
+
+        let stream = std::iter::from_fn(move || {
+            if done {
+                return None;
+            }
+
+            let mut next_keyed = || -> Option<Result<(Value, Value), LabeledError>> {
+                let value = match elements.next()? {
+                    Ok(value) => value,
+                    Err(error) => return Some(Err(error)),
+                };
+                let key = function.call(&plugin, &engine, vec![value.clone()], Some(value.clone()));
+                Some(key.map(|key| (key, value)))
+            };
+
+            let (key, first) = match pending.take().or_else(&mut next_keyed)? {
+                Ok(keyed) => keyed,
+                Err(error) => {
+                    done = true;
+                    return Some(Value::error(error.into(), head));
+                }
+            };
+
+            let mut items = vec![first];
+            loop {
+                if let Err(error) = group_signals.check(&head) {
+                    done = true;
+                    return Some(Value::error(error, head));
+                }
+                match next_keyed() {
+                    None => {
+                        done = true;
+                        break;
+                    }
+                    Some(Ok((next_key, value))) if next_key == key => items.push(value),
+                    next => {
+                        pending = next;
+                        break;
+                    }
+                }
+            }
+
+            Some(Value::record(
+                record! {
+                    "key" => key,
+                    "items" => Value::list(items, head),
+                },
+                head,
+            ))
+        });
+
+        Ok(PipelineData::ListStream(
+            ListStream::new(stream, head, signals),
+            None,
+        ))
+    }
+
+    fn examples(&self) -> Vec<nu_protocol::Example<'_>> {
+        vec![
+            Example {
+                description: "Group consecutive numbers by parity",
+                example: "[1, 3, 2, 4, 5] | fp chunk-by {|x| $x mod 2}",
+                result: Some(Value::test_list(vec![
+                    Value::test_record(record! {
+                        "key" => Value::test_int(1),
+                        "items" => Value::test_list(vec![Value::test_int(1), Value::test_int(3)]),
+                    }),
+                    Value::test_record(record! {
+                        "key" => Value::test_int(0),
+                        "items" => Value::test_list(vec![Value::test_int(2), Value::test_int(4)]),
+                    }),
+                    Value::test_record(record! {
+                        "key" => Value::test_int(1),
+                        "items" => Value::test_list(vec![Value::test_int(5)]),
+                    }),
+                ])),
+            },
+            Example {
+                description: "Group consecutive log lines by level",
+                example: r#"open app.log | lines | fp chunk-by {|line| $line | parse "[{level}]{rest}" | get level.0? }"#,
+                result: None,
+            },
+        ]
+    }
+}
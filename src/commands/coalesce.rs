@@ -0,0 +1,112 @@
+use nu_plugin::SimplePluginCommand;
+use nu_protocol::{Category, Example, LabeledError, Signature, SyntaxShape, Type, Value};
+
+use crate::{FpPlugin, config::PureConfig, values::MaybeValue};
+
+use super::other_::{eval_fallback, present};
+
+#[derive(Clone)]
+pub struct Coalesce;
+
+impl SimplePluginCommand for Coalesce {
+    type Plugin = FpPlugin;
+
+    fn name(&self) -> &str {
+        "fp coalesce"
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build(self.name())
+            .input_output_type(Type::Any, Type::Any)
+            .rest(
+                "values",
+                SyntaxShape::Any,
+                "The values (or how to produce the values) to try in order when input is `null`.",
+            )
+            .switch(
+                "pure",
+                "Also skip values which `fp pure` converts to `null`",
+                Some('p'),
+            )
+            .category(Category::Conversions)
+    }
+
+    fn description(&self) -> &str {
+        "Use the first value which is not `null` from input and several fallbacks."
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"Return the input if it is not `null`, otherwise try each fallback in order and return the first one which is not `null`, return `null` if all of them are `null`.
+
+Each fallback can be a direct value, or a closure producing the value, same as `fp other`. Closures are only called when all values before them are `null`, and receive the input as pipeline input.
+
+With `--pure`, empty values like `0`, `''` and `[]` are skipped too, following the rules of `fp pure`, and a closure receives the original input value."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["default", "fallback", "first", "null"]
+    }
+
+    fn run(
+        &self,
+        plugin: &Self::Plugin,
+        engine: &nu_plugin::EngineInterface,
+        call: &nu_plugin::EvaluatedCall,
+        input: &Value,
+    ) -> Result<Value, LabeledError> {
+        let config = if call.has_flag("pure")? {
+            Some(plugin.config::<PureConfig>(engine)?)
+        } else {
+            None
+        };
+        if let Some(value) = present(input, config.as_ref()) {
+            return Ok(value.clone());
+        }
+
+        // Same as `fp other`, closures receive the input unless it is `fp none`, so with `--pure`
+        // they get the original empty value.
+        let fallback_input = match MaybeValue::try_from_value(input) {
+            Some(_) => Value::nothing(call.head),
+            None => input.clone(),
+        };
+        for fallback in call.rest::<Value>(0)? {
+            engine.signals().check(&call.head)?;
+            let value = eval_fallback(plugin, engine, fallback, &fallback_input)?;
+            if let Some(value) = present(&value, config.as_ref()) {
+                return Ok(value.clone());
+            }
+        }
+
+        Ok(Value::nothing(call.head))
+    }
+
+    fn examples(&self) -> Vec<nu_protocol::Example<'_>> {
+        vec![
+            Example {
+                description: "Use the first value which is not null",
+                example: "null | fp coalesce null 1 2",
+                result: Some(Value::test_int(1)),
+            },
+            Example {
+                description: "Input is not null",
+                example: "0 | fp coalesce 1 2",
+                result: Some(Value::test_int(0)),
+            },
+            Example {
+                description: "Only call closures when needed",
+                example: "let foo = 100; null | fp coalesce {|| null} {|| $foo + 2} {|| error make {msg: 'not called'}}",
+                result: Some(Value::test_int(102)),
+            },
+            Example {
+                description: "Skip empty values",
+                example: "'' | fp coalesce --pure 0 [] 'foo'",
+                result: Some(Value::test_string("foo")),
+            },
+            Example {
+                description: "Closures receive the empty input",
+                example: "'' | fp coalesce --pure null {|| $in + 'foo'}",
+                result: Some(Value::test_string("foo")),
+            },
+        ]
+    }
+}
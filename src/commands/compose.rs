@@ -0,0 +1,65 @@
+use nu_plugin::SimplePluginCommand;
+use nu_protocol::{Category, Example, LabeledError, Signature, SyntaxShape, Type, Value};
+
+use crate::{FpPlugin, values::FunctionValue};
+
+#[derive(Clone)]
+pub struct Compose;
+
+impl SimplePluginCommand for Compose {
+    type Plugin = FpPlugin;
+
+    fn name(&self) -> &str {
+        "fp compose"
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build(self.name())
+            .input_output_type(Type::Nothing, Type::Custom("function".into()))
+            .rest(
+                "functions",
+                SyntaxShape::OneOf(vec![SyntaxShape::Closure(None), SyntaxShape::Any]),
+                "The closures or functions to compose.",
+            )
+            .category(Category::Custom("functional".into()))
+    }
+
+    fn description(&self) -> &str {
+        "Compose closures into a function."
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"Returns a function calling the closures (or functions) from right to left, the same as function composition in mathematics: `fp compose $f $g` calls `$g` first and then `$f` with its output.
+
+Use `fp call` to call the function, or `fp pipe` to call closures from left to right directly."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["function", "closure", "combine", "point-free"]
+    }
+
+    fn run(
+        &self,
+        _plugin: &Self::Plugin,
+        _engine: &nu_plugin::EngineInterface,
+        call: &nu_plugin::EvaluatedCall,
+        _input: &Value,
+    ) -> Result<Value, LabeledError> {
+        let mut functions = call
+            .rest::<Value>(0)?
+            .into_iter()
+            .map(FunctionValue::from_callable)
+            .collect::<Result<Vec<_>, _>>()?;
+        functions.reverse();
+
+        Ok(FunctionValue::Pipe(functions).into_value(call.head))
+    }
+
+    fn examples(&self) -> Vec<nu_protocol::Example<'_>> {
+        vec![Example {
+            description: "Compose two closures, the right one is called first",
+            example: "let f = fp compose {|x| $x + 1} {|x| $x * 2}; 5 | fp call $f",
+            result: Some(Value::test_int(11)),
+        }]
+    }
+}
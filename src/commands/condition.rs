@@ -0,0 +1,121 @@
+//! Shared handling of the row condition (or closure) argument used by `first-where` like commands.
+
+use nu_plugin::EngineInterface;
+use nu_protocol::{LabeledError, PipelineData, Type, Value};
+
+use crate::{FpPlugin, values::FunctionValue};
+
+/// Input and output types of commands finding an element in `list`, `table` or `range`.
+///
+/// `item` is the output type when finding from a list or range, `row` is the output type when
+/// finding from a table, both of them can be `null` if no element is found.
+pub fn find_input_output_types(item: Type, row: Type) -> Vec<(Type, Type)> {
+    vec![
+        (
+            Type::List(Box::new(Type::Any)),
+            Type::OneOf(Box::new([item.clone(), Type::Nothing])),
+        ),
+        (Type::table(), Type::OneOf(Box::new([row, Type::Nothing]))),
+        (Type::Range, Type::OneOf(Box::new([item, Type::Nothing]))),
+    ]
+}
+
+/// Iterate over the elements in input.
+///
+/// List streams are consumed lazily, byte streams are iterated line by line. Dropping
+/// the iterator drops the stream, which stops the upstream producer.
+pub fn elements(
+    input: PipelineData,
+) -> Box<dyn Iterator<Item = Result<Value, LabeledError>> + Send> {
+    match input {
+        PipelineData::Empty => Box::new(std::iter::empty()),
+        PipelineData::ListStream(stream, ..) => Box::new(stream.into_iter().map(Ok)),
+        PipelineData::ByteStream(stream, ..) => {
+            let span = stream.span();
+            match stream.lines() {
+                Some(lines) => Box::new(lines.map(move |line| Ok(Value::string(line?, span)))),
+                None => Box::new(std::iter::empty()),
+            }
+        }
+        input => Box::new(input.into_iter().map(Ok)),
+    }
+}
+
+/// A row condition or closure checked on each element.
+#[derive(Clone)]
+pub struct Condition {
+    function: FunctionValue,
+
+    /// Pass the index of element as the second parameter of closure.
+    pass_index: bool,
+}
+
+impl Condition {
+    /// Create a condition from a row condition, closure or function value.
+    pub fn new(value: Value) -> Result<Self, LabeledError> {
+        Ok(Self {
+            function: FunctionValue::from_callable(value)?,
+            pass_index: false,
+        })
+    }
+
+    pub fn pass_index(mut self, pass_index: bool) -> Self {
+        self.pass_index = pass_index;
+        self
+    }
+
+    /// Check if `value` at `index` meets the condition.
+    pub fn meets(
+        &self,
+        plugin: &FpPlugin,
+        engine: &EngineInterface,
+        index: usize,
+        value: &Value,
+    ) -> Result<bool, LabeledError> {
+        let mut args = vec![value.clone()];
+        if self.pass_index {
+            args.push(Value::int(index as i64, value.span()));
+        }
+        let result = self
+            .function
+            .call(plugin, engine, args, Some(value.clone()))?;
+        Ok(result.is_true())
+    }
+
+    /// Find the first element meeting the condition, returns the index and the element.
+    pub fn find_first(
+        &self,
+        plugin: &FpPlugin,
+        engine: &EngineInterface,
+        input: PipelineData,
+    ) -> Result<Option<(usize, Value)>, LabeledError> {
+        for (index, value) in elements(input).enumerate() {
+            let value = value?;
+            engine.signals().check(&value.span())?;
+            if self.meets(plugin, engine, index, &value)? {
+                return Ok(Some((index, value)));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Find the last element meeting the condition, returns the index and the element.
+    ///
+    /// All elements are checked, so the input must be finite.
+    pub fn find_last(
+        &self,
+        plugin: &FpPlugin,
+        engine: &EngineInterface,
+        input: PipelineData,
+    ) -> Result<Option<(usize, Value)>, LabeledError> {
+        let mut found = None;
+        for (index, value) in elements(input).enumerate() {
+            let value = value?;
+            engine.signals().check(&value.span())?;
+            if self.meets(plugin, engine, index, &value)? {
+                found = Some((index, value));
+            }
+        }
+        Ok(found)
+    }
+}
@@ -0,0 +1,113 @@
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    Category, Example, LabeledError, ListStream, PipelineData, Signature, SyntaxShape, Type, Value,
+};
+
+use crate::FpPlugin;
+
+use super::condition::{Condition, elements};
+
+#[derive(Clone)]
+pub struct DropWhile;
+
+impl PluginCommand for DropWhile {
+    type Plugin = FpPlugin;
+
+    fn name(&self) -> &str {
+        "fp drop-while"
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![
+                (
+                    Type::List(Box::new(Type::Any)),
+                    Type::List(Box::new(Type::Any)),
+                ),
+                (Type::table(), Type::table()),
+                (Type::Range, Type::List(Box::new(Type::Any))),
+            ])
+            .required(
+                "condition",
+                SyntaxShape::RowCondition,
+                "Row condition or closure the dropped elements satify.",
+            )
+            .category(Category::Filters)
+    }
+
+    fn description(&self) -> &str {
+        "Drop elements while they meet a condition."
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"Drop elements from the start of input until the first element not meeting the condition, output that element and all the rest.
+
+The output is produced lazily, the condition is not checked anymore after the first element not meeting it."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["skip", "suffix", "condition"]
+    }
+
+    fn run(
+        &self,
+        plugin: &Self::Plugin,
+        engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let condition = Condition::new(call.req(0)?)?;
+        let signals = engine.signals().clone();
+        let plugin = plugin.clone();
+        let engine = engine.clone();
+        let head = call.head;
+
+        let mut dropping = true;
+        let mut done = false;
+        let stream = elements(input)
+            .enumerate()
+            .filter_map(move |(index, value)| {
+                if done {
+                    return None;
+                }
+                let result = value.and_then(|value| {
+                    if dropping && condition.meets(&plugin, &engine, index, &value)? {
+                        Ok(None)
+                    } else {
+                        dropping = false;
+                        Ok(Some(value))
+                    }
+                });
+                match result {
+                    Ok(value) => value,
+                    Err(error) => {
+                        done = true;
+                        Some(Value::error(error.into(), head))
+                    }
+                }
+            });
+
+        Ok(PipelineData::ListStream(
+            ListStream::new(stream, head, signals),
+            None,
+        ))
+    }
+
+    fn examples(&self) -> Vec<nu_protocol::Example<'_>> {
+        vec![
+            Example {
+                description: "Drop elements while they are smaller than 5",
+                example: "[1, 2, 8, 4] | fp drop-while $it < 5",
+                result: Some(Value::test_list(vec![
+                    Value::test_int(8),
+                    Value::test_int(4),
+                ])),
+            },
+            Example {
+                description: "Drop rows while rank is larger than 5, using closure as condition",
+                example: r#"[{name: "Alice", rank: 10}, {name: "Bob", rank: 7}, {name: "Carol", rank: 3}] | fp drop-while {|x| $x.rank > 5} | get name"#,
+                result: Some(Value::test_list(vec![Value::test_string("Carol")])),
+            },
+        ]
+    }
+}
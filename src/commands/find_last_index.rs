@@ -0,0 +1,87 @@
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    Category, Example, LabeledError, PipelineData, Signature, SyntaxShape, Type, Value,
+};
+
+use crate::FpPlugin;
+
+use super::condition::{Condition, find_input_output_types};
+
+#[derive(Clone)]
+pub struct FindLastIndex;
+
+impl PluginCommand for FindLastIndex {
+    type Plugin = FpPlugin;
+
+    fn name(&self) -> &str {
+        "fp find-last-index"
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build(self.name())
+            .input_output_types(find_input_output_types(Type::Int, Type::Int))
+            .required(
+                "condition",
+                SyntaxShape::RowCondition,
+                "Row condition or closure to filter the last element satify.",
+            )
+            .category(Category::Filters)
+    }
+
+    fn description(&self) -> &str {
+        "Find the index of the last element which meets a condition."
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"Find the index of the last element which meets a condition, returns `null` if no element meets the condition.
+
+Supported input types:
+
+* `list`
+* `table`
+* `range`
+
+Pipe text through `lines` to check it line by line."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["filter", "find", "search", "condition", "position"]
+    }
+
+    fn run(
+        &self,
+        plugin: &Self::Plugin,
+        engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let closure = call.req(0)?;
+
+        let value = match Condition::new(closure)?.find_last(plugin, engine, input)? {
+            Some((index, _)) => Value::int(index as i64, call.head),
+            None => Value::nothing(call.head),
+        };
+
+        Ok(PipelineData::Value(value, None))
+    }
+
+    fn examples(&self) -> Vec<nu_protocol::Example<'_>> {
+        vec![
+            Example {
+                description: "Find the index of the last element in a list that larger than 5",
+                example: "[1, 2, 4, 8, 16] | fp find-last-index $it > 5",
+                result: Some(Value::test_int(4)),
+            },
+            Example {
+                description: "Find the index of the last element in a list that larger than 5, null result",
+                example: "[1, 2, 4] | fp find-last-index {|x| $x > 5}",
+                result: Some(Value::test_nothing()),
+            },
+            Example {
+                description: "Remove the last row in a table that rank smaller than 8",
+                example: r#"let t = [{name: "Alice", rank: 10}, {name: "Bob", rank: 7}]; $t | drop nth ($t | fp find-last-index $it.rank < 8)"#,
+                result: None,
+            },
+        ]
+    }
+}
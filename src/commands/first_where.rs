@@ -3,7 +3,16 @@ use nu_protocol::{
     Category, Example, LabeledError, PipelineData, Signature, SyntaxShape, Type, Value, record,
 };
 
-use crate::FpPlugin;
+use crate::{
+    FpPlugin,
+    config::{FirstWhereConfig, NotFound},
+    values::MaybeValue,
+};
+
+use super::{
+    condition::{Condition, find_input_output_types},
+    other_::eval_fallback,
+};
 
 #[derive(Clone)]
 pub struct FirstWhere;
@@ -18,25 +27,39 @@ impl PluginCommand for FirstWhere {
 
     fn signature(&self) -> nu_protocol::Signature {
         Signature::build(self.name())
-            .input_output_types(vec![
-                (
-                    Type::List(Box::new(Type::Any)),
-                    Type::OneOf(Box::new([Type::Any, Type::Nothing])),
-                ),
-                (
-                    Type::table(),
-                    Type::OneOf(Box::new([Type::record(), Type::Nothing])),
-                ),
-                (
-                    Type::Range,
-                    Type::OneOf(Box::new([Type::Any, Type::Nothing])),
-                ),
-            ])
+            .input_output_types(find_input_output_types(Type::Any, Type::record()))
             .required(
                 "condition",
                 SyntaxShape::RowCondition, // RowCondition covers Clousure type.
                 "Row condition or closure to filter the first element satify.",
             )
+            .switch(
+                "index",
+                "Pass the index of element to the closure as the second parameter",
+                Some('i'),
+            )
+            .switch(
+                "with-index",
+                "Return a record of `{index, item}` instead of the element",
+                Some('w'),
+            )
+            .switch(
+                "maybe",
+                "Return `fp some <element>` or `fp none` instead of the element or `null`",
+                Some('m'),
+            )
+            .named(
+                "default",
+                SyntaxShape::Any,
+                "The value (or closure to produce the value) to use when no element meets the condition",
+                Some('d'),
+            )
+            .named(
+                "error",
+                SyntaxShape::String,
+                "Raise an error with the message when no element meets the condition",
+                Some('e'),
+            )
             .category(Category::Filters)
     }
 
@@ -51,7 +74,22 @@ Supported input types:
 
 * `list`
 * `table`
-* `range`"#
+* `range`
+
+Pipe text through `lines` to check it line by line.
+
+Streams are consumed lazily and dropped as soon as an element is found, so upstream commands stop
+producing, and infinite streams are supported.
+
+With `--index`, the closure receives the index of element as the second parameter, e.g. `{|x, i| ...}`.
+With `--with-index`, returns `{index, item}` like `enumerate` does, without enumerating the whole input.
+With `--maybe`, returns `fp some <element>` or `fp none`, so a `null` element is not confused with no element found.
+
+When no element meets the condition, `--default` is used instead of `null`, it can be a value or a
+closure producing the value, same as `fp other`. Or use `--error` to raise an error.
+
+Without these flags, set `$env.config.plugins.functional.first_where.not_found` to "error" to raise
+an error by default."#
     }
 
     fn search_terms(&self) -> Vec<&str> {
@@ -60,22 +98,71 @@ Supported input types:
 
     fn run(
         &self,
-        _plugin: &Self::Plugin,
+        plugin: &Self::Plugin,
         engine: &EngineInterface,
         call: &EvaluatedCall,
         input: PipelineData,
     ) -> Result<PipelineData, LabeledError> {
         // ref: https://github.com/nushell/plugin-examples/blob/main/rust/nu_plugin_example/src/commands/for_each.rs
         let closure = call.req(0)?;
-
-        for value in input {
-            let result = engine.eval_closure(&closure, vec![value.clone()], Some(value.clone()))?;
-            if result.is_true() {
-                return Ok(PipelineData::Value(value, None));
-            }
+        let with_index = call.has_flag("with-index")?;
+        let default = call.get_flag::<Value>("default")?;
+        let error = call.get_flag::<String>("error")?;
+        let maybe = call.has_flag("maybe")?;
+        if default.is_some() && error.is_some() {
+            return Err(LabeledError::new("Conflicting flags").with_label(
+                "`--default` and `--error` can not be used together",
+                call.head,
+            ));
         }
+        if maybe && (default.is_some() || error.is_some()) {
+            return Err(LabeledError::new("Conflicting flags").with_label(
+                "`--maybe` can not be used with `--default` or `--error`",
+                call.head,
+            ));
+        }
+
+        let found = Condition::new(closure)?
+            .pass_index(call.has_flag("index")?)
+            .find_first(plugin, engine, input)?
+            .map(|(index, item)| {
+                if with_index {
+                    Value::record(
+                        record! {
+                            "index" => Value::int(index as i64, call.head),
+                            "item" => item,
+                        },
+                        call.head,
+                    )
+                } else {
+                    item
+                }
+            });
+
+        let value = match found {
+            Some(item) if maybe => MaybeValue::Some(item).into_value(call.head),
+            Some(item) => item,
+            None if maybe => MaybeValue::None.into_value(call.head),
+            None => match (default, error) {
+                (Some(default), _) => {
+                    eval_fallback(plugin, engine, default, &Value::nothing(call.head))?
+                }
+                (None, Some(error)) => {
+                    return Err(LabeledError::new(error)
+                        .with_label("no element meets the condition", call.head));
+                }
+                (None, None) => match plugin.config::<FirstWhereConfig>(engine)?.not_found {
+                    NotFound::Null => Value::nothing(call.head),
+                    NotFound::Error => {
+                        return Err(LabeledError::new("No element meets the condition")
+                            .with_label("no element meets the condition", call.head)
+                            .with_help("`$env.config.plugins.functional.first_where.not_found` is set to \"error\""));
+                    }
+                },
+            },
+        };
 
-        Ok(PipelineData::Value(Value::nothing(call.head), None))
+        Ok(PipelineData::Value(value, None))
     }
 
     fn examples(&self) -> Vec<nu_protocol::Example<'_>> {
@@ -121,6 +208,49 @@ Supported input types:
                 example: "1..10 | fp first-where $it > 5",
                 result: Some(Value::test_int(6)),
             },
+            Example {
+                description: "Filter the first element in an infinite range that larger than 1000",
+                example: "1.. | fp first-where $it > 1000",
+                result: Some(Value::test_int(1001)),
+            },
+            Example {
+                description: "Filter the first line in a string that contains 'b'",
+                example: r#""foo\nbar\nbaz" | lines | fp first-where $it =~ "b""#,
+                result: Some(Value::test_string("bar")),
+            },
+            Example {
+                description: "Find the first null element, distinguished from no element found",
+                example: "[1, null, 2] | fp first-where --maybe $it == null | fp then 'found' | fp other 'missing'",
+                result: Some(Value::test_string("found")),
+            },
+            Example {
+                description: "Filter the first element in a list that larger than 5, use 0 if not found",
+                example: "[1, 2, 4] | fp first-where --default 0 $it > 5",
+                result: Some(Value::test_int(0)),
+            },
+            Example {
+                description: "Filter the first element in a list that larger than 5, produce the default value with closure",
+                example: "let foo = 100; [1, 2, 4] | fp first-where --default {|| $foo + 2} $it > 5",
+                result: Some(Value::test_int(102)),
+            },
+            Example {
+                description: "Filter the first element in a list that larger than 5, raise an error if not found",
+                example: r#"[1, 2, 4] | fp first-where --error "no element larger than 5" $it > 5"#,
+                result: None,
+            },
+            Example {
+                description: "Filter the first element larger than its index",
+                example: "[0, 0, 5, 1] | fp first-where --index {|x, i| $x > $i}",
+                result: Some(Value::test_int(5)),
+            },
+            Example {
+                description: "Filter the first element larger than 5 and its index",
+                example: "[1, 2, 4, 8] | fp first-where --with-index $it > 5",
+                result: Some(Value::test_record(record! {
+                    "index" => Value::test_int(3),
+                    "item" => Value::test_int(8),
+                })),
+            },
         ]
     }
 }
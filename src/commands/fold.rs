@@ -0,0 +1,123 @@
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    Category, Example, LabeledError, PipelineData, Signature, SyntaxShape, Type, Value,
+};
+
+use crate::{
+    FpPlugin,
+    values::{BreakValue, FunctionValue},
+};
+
+#[derive(Clone)]
+pub struct Fold;
+
+impl PluginCommand for Fold {
+    type Plugin = FpPlugin;
+
+    fn name(&self) -> &str {
+        "fp fold"
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![
+                (Type::List(Box::new(Type::Any)), Type::Any),
+                (Type::table(), Type::Any),
+                (Type::Range, Type::Any),
+            ])
+            .required("init", SyntaxShape::Any, "The initial accumulator.")
+            .required(
+                "closure",
+                SyntaxShape::OneOf(vec![
+                    SyntaxShape::Closure(Some(vec![SyntaxShape::Any, SyntaxShape::Any])),
+                    SyntaxShape::Any,
+                ]),
+                "The closure to combine the accumulator and an element into the next accumulator.",
+            )
+            .switch(
+                "right",
+                "Fold from the last element to the first",
+                Some('r'),
+            )
+            .category(Category::Filters)
+    }
+
+    fn description(&self) -> &str {
+        "Combine elements into a single value with an initial accumulator."
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"Call the closure `{|acc, x| ...}` on each element with the accumulator, the result becomes the accumulator for the next element, returns the final accumulator.
+
+The accumulator is also passed as pipeline input of the closure.
+
+With `--right`, elements are folded from the last one to the first one, the whole input is collected first.
+
+Return `fp break <value>` from the closure to stop early, the value becomes the result."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec![
+            "reduce",
+            "aggregate",
+            "accumulate",
+            "foldl",
+            "foldr",
+            "reduce-right",
+        ]
+    }
+
+    fn run(
+        &self,
+        plugin: &Self::Plugin,
+        engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let mut acc: Value = call.req(0)?;
+        let function = FunctionValue::from_callable(call.req(1)?)?;
+
+        let elements: Box<dyn Iterator<Item = Value>> = if call.has_flag("right")? {
+            // The whole input is collected first, check signals so an infinite stream can be
+            // interrupted.
+            let mut values = vec![];
+            for value in input {
+                engine.signals().check(&call.head)?;
+                values.push(value);
+            }
+            Box::new(values.into_iter().rev())
+        } else {
+            Box::new(input.into_iter())
+        };
+
+        for value in elements {
+            engine.signals().check(&call.head)?;
+            acc = function.call(plugin, engine, vec![acc.clone(), value], Some(acc))?;
+            if let Some(value) = BreakValue::try_from_value(&acc) {
+                return Ok(PipelineData::Value(value.clone(), None));
+            }
+        }
+
+        Ok(PipelineData::Value(acc, None))
+    }
+
+    fn examples(&self) -> Vec<nu_protocol::Example<'_>> {
+        vec![
+            Example {
+                description: "Sum the elements in a list",
+                example: "[1, 2, 3, 4] | fp fold 0 {|acc, x| $acc + $x}",
+                result: Some(Value::test_int(10)),
+            },
+            Example {
+                description: "Concatenate the elements from right to left",
+                example: "[a, b, c] | fp fold --right '' {|acc, x| $acc + $x}",
+                result: Some(Value::test_string("cba")),
+            },
+            Example {
+                description: "Sum the elements until the sum is larger than 5",
+                example: "[1, 2, 4, 8] | fp fold 0 {|acc, x| if $acc > 5 { fp break $acc } else { $acc + $x } }",
+                result: Some(Value::test_int(7)),
+            },
+        ]
+    }
+}
@@ -1,7 +1,12 @@
 use nu_plugin::SimplePluginCommand;
-use nu_protocol::{Category, Example, Signature, SyntaxShape, Type, Value};
+use nu_protocol::{
+    Category, Example, LabeledError, Signature, Spanned, SyntaxShape, Type, Value,
+    ast::{CellPath, PathMember},
+    casing::Casing,
+    record,
+};
 
-use crate::FpPlugin;
+use crate::{FpPlugin, type_pattern::TypePattern};
 
 #[derive(Clone)]
 pub struct Is;
@@ -15,8 +20,21 @@ impl SimplePluginCommand for Is {
 
     fn signature(&self) -> nu_protocol::Signature {
         Signature::build(SimplePluginCommand::name(self))
-            .input_output_type(Type::Any, Type::Bool)
+            .input_output_type(
+                Type::Any,
+                Type::OneOf(Box::new([Type::Bool, Type::record()])),
+            )
             .required("type", SyntaxShape::String, "The expected type")
+            .switch(
+                "explain",
+                "Return a record describing where the input does not match instead of a bool",
+                Some('e'),
+            )
+            .switch(
+                "all",
+                "With --explain, report all mismatches instead of the first one",
+                Some('a'),
+            )
             .category(Category::Formats)
     }
 
@@ -25,7 +43,28 @@ impl SimplePluginCommand for Is {
     }
 
     fn extra_description(&self) -> &str {
-        r#"For any input data, check the data is a type or not, returns true if is."#
+        r#"For any input data, check the data is a type or not, returns true if is.
+
+The type is a pattern written in nushell type syntax and checked structurally:
+
+* `int`, `string`, `range`, `closure`, `cell-path`, ...: any nushell type name.
+* `number`: either int or float.
+* `date`, `datetime`: date.
+* `custom<function>`: plugin custom values with the type name, e.g. `function`, `maybe` or `result`.
+* `list<int>`: a list where every element is int.
+* `record<name: string, rank: int>`: a record with field `name` of string and `rank` of int.
+* `record<name, rank>`: field types can be omitted, only the field names are checked.
+* `record<rank, name>`: field order does not matter, extra fields are allowed.
+* `table<name: string>`: a list where every row is a record matching the columns.
+* `int | float`, `oneof<int, float>`: either int or float.
+* `!nothing`: anything except `null`, use parentheses to negate a union: `!(int | float)`.
+
+Whitespaces in the pattern are ignored, unknown type names are errors.
+
+With `--explain`, returns a record `{matched: bool, mismatches: list}` instead, each mismatch is a
+record of `path` (cell path to the mismatched value), `expected` (the expected pattern) and
+`actual` (type of the value, or `missing` for a missing field). Only the first mismatch is reported
+unless `--all` is set."#
     }
 
     fn search_terms(&self) -> Vec<&str> {
@@ -38,39 +77,39 @@ impl SimplePluginCommand for Is {
         _engine: &nu_plugin::EngineInterface,
         call: &nu_plugin::EvaluatedCall,
         input: &Value,
-    ) -> Result<Value, nu_protocol::LabeledError> {
-        let target_type = call.req::<String>(0)?;
-        let input_type = input.get_type();
-        let ty = input_type.to_string();
-
-        // TODO: Check on generic types with semantic type meaning.
-        //
-        // e.g.
-        //
-        // `{name: "Alice", rank: 10} | fp is 'record<name: string, rank: int>' shall be true
-        // `{name: "Alice", rank: 10} | fp is 'record<name:string,rank:int>' shall be true, despite the spaces.
-        // `{name: "Alice", rank: 10} | fp is 'record<name, rank>' shall be true, without specifying field types.
-        // `{name: "Alice", rank: 10} | fp is 'record<name: string, rank>' shall be true, with partial specified field types.
-        // `{name: "Alice", rank: 10} | fp is 'record<rank, name>' shall be true, with unordered field names.
-
-        let table_check = if target_type.as_str() == "table" {
-            input_type.is_subtype_of(&Type::table())
-        } else {
-            false
-        };
-        let record_check = if target_type.as_str() == "record" {
-            input_type.is_subtype_of(&Type::record())
-        } else {
-            false
-        };
-        let list_check = if target_type.as_str() == "list" {
-            input_type.is_subtype_of(&Type::list(Type::Any))
-        } else {
-            false
-        };
-
-        Ok(Value::bool(
-            ty == target_type || table_check || record_check || list_check,
+    ) -> Result<Value, LabeledError> {
+        let pattern = TypePattern::parse_spanned(&call.req::<Spanned<String>>(0)?)?;
+        let explain = call.has_flag("explain")?;
+        let all = call.has_flag("all")?;
+        if all && !explain {
+            return Err(LabeledError::new("Missing flag")
+                .with_label("`--all` requires `--explain`", call.head));
+        }
+
+        if !explain {
+            return Ok(Value::bool(pattern.matches(input), call.head));
+        }
+
+        let mismatches = pattern
+            .mismatches(input, all)
+            .into_iter()
+            .map(|m| {
+                Value::record(
+                    record! {
+                        "path" => Value::cell_path(CellPath { members: m.path }, call.head),
+                        "expected" => Value::string(m.expected, call.head),
+                        "actual" => Value::string(m.actual, call.head),
+                    },
+                    call.head,
+                )
+            })
+            .collect::<Vec<_>>();
+
+        Ok(Value::record(
+            record! {
+                "matched" => Value::bool(mismatches.is_empty(), call.head),
+                "mismatches" => Value::list(mismatches, call.head),
+            },
             call.head,
         ))
     }
@@ -97,6 +136,68 @@ impl SimplePluginCommand for Is {
                 example: "[1, 2] | fp is list<int>",
                 result: Some(Value::test_bool(true)),
             },
+            Example {
+                description: "Check input is a record with specified fields",
+                example: r#"{name: "Alice", rank: 10} | fp is 'record<name: string, rank: int>'"#,
+                result: Some(Value::test_bool(true)),
+            },
+            Example {
+                description: "Check input is a record with partially typed and unordered fields",
+                example: r#"{name: "Alice", rank: 10} | fp is 'record<rank,name:string>'"#,
+                result: Some(Value::test_bool(true)),
+            },
+            Example {
+                description: "Check input is a table with specified columns",
+                example: r#"[{name: "Alice", rank: 10}] | fp is 'list<record<name: string>>'"#,
+                result: Some(Value::test_bool(true)),
+            },
+            Example {
+                description: "Check input is a number",
+                example: "1.5 | fp is number",
+                result: Some(Value::test_bool(true)),
+            },
+            Example {
+                description: "Check input is a range",
+                example: "1..10 | fp is range",
+                result: Some(Value::test_bool(true)),
+            },
+            Example {
+                description: "Check input is a function value",
+                example: "fp compose {|x| $x + 1} | fp is 'custom<function>'",
+                result: Some(Value::test_bool(true)),
+            },
+            Example {
+                description: "Check input is int or string",
+                example: "'foo' | fp is 'int | string'",
+                result: Some(Value::test_bool(true)),
+            },
+            Example {
+                description: "Check input is not null",
+                example: "null | fp is '!nothing'",
+                result: Some(Value::test_bool(false)),
+            },
+            Example {
+                description: "Check input is a list of numbers or strings",
+                example: "[1, 'a', 2.5] | fp is 'list<oneof<int, float, string>>'",
+                result: Some(Value::test_bool(true)),
+            },
+            Example {
+                description: "Explain why a table does not match",
+                example: r#"[{name: "Alice", rank: 10}, {name: "Bob"}] | fp is --explain 'table<name: string, rank: int>'"#,
+                result: Some(Value::test_record(record! {
+                    "matched" => Value::test_bool(false),
+                    "mismatches" => Value::test_list(vec![Value::test_record(record! {
+                        "path" => Value::test_cell_path(CellPath {
+                            members: vec![
+                                PathMember::test_int(1, false),
+                                PathMember::test_string("rank".into(), false, Casing::Sensitive),
+                            ],
+                        }),
+                        "expected" => Value::test_string("int"),
+                        "actual" => Value::test_string("missing"),
+                    })]),
+                })),
+            },
         ]
     }
 }
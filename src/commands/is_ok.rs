@@ -0,0 +1,55 @@
+use nu_plugin::SimplePluginCommand;
+use nu_protocol::{Category, Example, LabeledError, Signature, Type, Value};
+
+use crate::{FpPlugin, values::ResultValue};
+
+#[derive(Clone)]
+pub struct IsOk;
+
+impl SimplePluginCommand for IsOk {
+    type Plugin = FpPlugin;
+
+    fn name(&self) -> &str {
+        "fp is-ok"
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build(self.name())
+            .input_output_type(Type::Custom("result".into()), Type::Bool)
+            .category(Category::Custom("functional".into()))
+    }
+
+    fn description(&self) -> &str {
+        "Check if a result is `ok`."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["result", "error", "success", "check"]
+    }
+
+    fn run(
+        &self,
+        _plugin: &Self::Plugin,
+        _engine: &nu_plugin::EngineInterface,
+        call: &nu_plugin::EvaluatedCall,
+        input: &Value,
+    ) -> Result<Value, LabeledError> {
+        let is_ok = matches!(ResultValue::from_value(input)?, ResultValue::Ok(_));
+        Ok(Value::bool(is_ok, call.head))
+    }
+
+    fn examples(&self) -> Vec<nu_protocol::Example<'_>> {
+        vec![
+            Example {
+                description: "Closure succeeding",
+                example: "'1' | fp try { into int } | fp is-ok",
+                result: Some(Value::test_bool(true)),
+            },
+            Example {
+                description: "Closure failing",
+                example: "'a' | fp try { into int } | fp is-ok",
+                result: Some(Value::test_bool(false)),
+            },
+        ]
+    }
+}
@@ -0,0 +1,114 @@
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    Category, Example, LabeledError, ListStream, PipelineData, Signature, SyntaxShape, Type, Value,
+};
+
+use crate::{FpPlugin, values::FunctionValue};
+
+#[derive(Clone)]
+pub struct Iterate;
+
+impl PluginCommand for Iterate {
+    type Plugin = FpPlugin;
+
+    fn name(&self) -> &str {
+        "fp iterate"
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build(self.name())
+            .input_output_type(Type::Nothing, Type::List(Box::new(Type::Any)))
+            .required("seed", SyntaxShape::Any, "The first element.")
+            .required(
+                "closure",
+                SyntaxShape::OneOf(vec![
+                    SyntaxShape::Closure(Some(vec![SyntaxShape::Any])),
+                    SyntaxShape::Any,
+                ]),
+                "The closure to produce the next element from the previous one.",
+            )
+            .category(Category::Generators)
+    }
+
+    fn description(&self) -> &str {
+        "Produce an infinite stream by applying a closure repeatedly to a seed."
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"Output the seed, then the result of calling the closure on the seed, then the result of calling the closure on that result, and so on.
+
+The stream is infinite and produced lazily, the closure is only called when the next element is needed. Use commands like `first`, `fp take-while` or `fp first-where` to limit it."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["generate", "repeat", "sequence", "stream"]
+    }
+
+    fn run(
+        &self,
+        plugin: &Self::Plugin,
+        engine: &EngineInterface,
+        call: &EvaluatedCall,
+        _input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let mut seed: Option<Value> = Some(call.req(0)?);
+        let function = FunctionValue::from_callable(call.req(1)?)?;
+        let signals = engine.signals().clone();
+        let plugin = plugin.clone();
+        let engine = engine.clone();
+        let head = call.head;
+
+        // The closure is called on the previous element only when the next one is pulled, so no
+        // extra call is made past what downstream consumes. After an error both are empty, which
+        // stops the stream.
+        let mut previous: Option<Value> = None;
+        let stream = std::iter::from_fn(move || {
+            let current = match previous.take() {
+                None => seed.take()?,
+                Some(previous) => {
+                    match function.call(&plugin, &engine, vec![previous.clone()], Some(previous)) {
+                        Ok(value) => value,
+                        Err(error) => return Some(Value::error(error.into(), head)),
+                    }
+                }
+            };
+            previous = Some(current.clone());
+            Some(current)
+        });
+
+        Ok(PipelineData::ListStream(
+            ListStream::new(stream, head, signals),
+            None,
+        ))
+    }
+
+    fn examples(&self) -> Vec<nu_protocol::Example<'_>> {
+        vec![
+            Example {
+                description: "Powers of 2",
+                example: "fp iterate 1 {|x| $x * 2} | first 5",
+                result: Some(Value::test_list(vec![
+                    Value::test_int(1),
+                    Value::test_int(2),
+                    Value::test_int(4),
+                    Value::test_int(8),
+                    Value::test_int(16),
+                ])),
+            },
+            Example {
+                description: "Collatz sequence of 6",
+                example: "fp iterate 6 {|x| if $x mod 2 == 0 { $x // 2 } else { $x * 3 + 1 }} | fp take-while $it != 1",
+                result: Some(Value::test_list(vec![
+                    Value::test_int(6),
+                    Value::test_int(3),
+                    Value::test_int(10),
+                    Value::test_int(5),
+                    Value::test_int(16),
+                    Value::test_int(8),
+                    Value::test_int(4),
+                    Value::test_int(2),
+                ])),
+            },
+        ]
+    }
+}
@@ -0,0 +1,95 @@
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    Category, Example, LabeledError, PipelineData, Signature, SyntaxShape, Type, Value, record,
+};
+
+use crate::FpPlugin;
+
+use super::condition::{Condition, find_input_output_types};
+
+#[derive(Clone)]
+pub struct LastWhere;
+
+impl PluginCommand for LastWhere {
+    type Plugin = FpPlugin;
+
+    fn name(&self) -> &str {
+        "fp last-where"
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build(self.name())
+            .input_output_types(find_input_output_types(Type::Any, Type::record()))
+            .required(
+                "condition",
+                SyntaxShape::RowCondition,
+                "Row condition or closure to filter the last element satify.",
+            )
+            .category(Category::Filters)
+    }
+
+    fn description(&self) -> &str {
+        "Find the last element which meets a condition."
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"Find the last element which meets a condition, returns `null` if no element meets the condition.
+
+Supported input types:
+
+* `list`
+* `table`
+* `range`
+
+Pipe text through `lines` to check it line by line."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["filter", "find", "search", "condition"]
+    }
+
+    fn run(
+        &self,
+        plugin: &Self::Plugin,
+        engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let closure = call.req(0)?;
+
+        let value = Condition::new(closure)?
+            .find_last(plugin, engine, input)?
+            .map(|(_, value)| value)
+            .unwrap_or(Value::nothing(call.head));
+
+        Ok(PipelineData::Value(value, None))
+    }
+
+    fn examples(&self) -> Vec<nu_protocol::Example<'_>> {
+        vec![
+            Example {
+                description: "Filter the last element in a list that smaller than 5",
+                example: "[1, 2, 4, 8] | fp last-where $it < 5",
+                result: Some(Value::test_int(4)),
+            },
+            Example {
+                description: "Filter the last element in a list that smaller than 5, using closure as condition",
+                example: "[1, 2, 4, 8] | fp last-where {|x| $x < 5}",
+                result: Some(Value::test_int(4)),
+            },
+            Example {
+                description: "Filter the last element in a list that smaller than 5, null result",
+                example: "[8, 16] | fp last-where $it < 5",
+                result: Some(Value::test_nothing()),
+            },
+            Example {
+                description: "Filter the last row in a table that rank larger than 5",
+                example: r#"[{name: "Alice", rank: 10}, {name: "Bob", rank: 7}] | fp last-where $it.rank > 5"#,
+                result: Some(Value::test_record(record! {
+                    "name" => Value::test_string("Bob"),
+                    "rank" => Value::test_int(7),
+                })),
+            },
+        ]
+    }
+}
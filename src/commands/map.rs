@@ -0,0 +1,82 @@
+use nu_plugin::SimplePluginCommand;
+use nu_protocol::{Category, Example, LabeledError, Signature, SyntaxShape, Type, Value};
+
+use crate::{
+    FpPlugin,
+    values::{FunctionValue, MaybeValue},
+};
+
+#[derive(Clone)]
+pub struct Map;
+
+impl SimplePluginCommand for Map {
+    type Plugin = FpPlugin;
+
+    fn name(&self) -> &str {
+        "fp map"
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build(self.name())
+            .input_output_type(Type::Custom("maybe".into()), Type::Custom("maybe".into()))
+            .required(
+                "closure",
+                SyntaxShape::OneOf(vec![
+                    SyntaxShape::Closure(Some(vec![SyntaxShape::Any])),
+                    SyntaxShape::Any,
+                ]),
+                "The closure to transform the present value.",
+            )
+            .category(Category::Custom("functional".into()))
+    }
+
+    fn description(&self) -> &str {
+        "Transform the value in a maybe value if it is present."
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"Call the closure on the value of `fp some`, the result is wrapped into `fp some` again, `fp none` stays `none`.
+
+The value is passed as both the parameter and the pipeline input of the closure."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["maybe", "option", "transform", "fmap"]
+    }
+
+    fn run(
+        &self,
+        plugin: &Self::Plugin,
+        engine: &nu_plugin::EngineInterface,
+        call: &nu_plugin::EvaluatedCall,
+        input: &Value,
+    ) -> Result<Value, LabeledError> {
+        let function = FunctionValue::from_callable(call.req(0)?)?;
+        let maybe = match MaybeValue::from_value(input)? {
+            MaybeValue::Some(value) => MaybeValue::Some(function.call(
+                plugin,
+                engine,
+                vec![value.clone()],
+                Some(value.clone()),
+            )?),
+            MaybeValue::None => MaybeValue::None,
+        };
+
+        Ok(maybe.into_value(call.head))
+    }
+
+    fn examples(&self) -> Vec<nu_protocol::Example<'_>> {
+        vec![
+            Example {
+                description: "Double a present value",
+                example: "fp some 2 | fp map {|x| $x * 2} | fp to-nullable",
+                result: Some(Value::test_int(4)),
+            },
+            Example {
+                description: "Absent value stays absent",
+                example: "fp none | fp map {|x| $x * 2} | fp unwrap-or 0",
+                result: Some(Value::test_int(0)),
+            },
+        ]
+    }
+}
@@ -0,0 +1,75 @@
+use nu_plugin::SimplePluginCommand;
+use nu_protocol::{Category, Example, LabeledError, Signature, SyntaxShape, Type, Value};
+
+use crate::{
+    FpPlugin,
+    values::{FunctionValue, ResultValue},
+};
+
+#[derive(Clone)]
+pub struct MapErr;
+
+impl SimplePluginCommand for MapErr {
+    type Plugin = FpPlugin;
+
+    fn name(&self) -> &str {
+        "fp map-err"
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build(self.name())
+            .input_output_type(Type::Custom("result".into()), Type::Custom("result".into()))
+            .required(
+                "closure",
+                SyntaxShape::OneOf(vec![
+                    SyntaxShape::Closure(Some(vec![SyntaxShape::Any])),
+                    SyntaxShape::Any,
+                ]),
+                "The closure to transform the error of `err`.",
+            )
+            .category(Category::Custom("functional".into()))
+    }
+
+    fn description(&self) -> &str {
+        "Transform the error of an `err` result."
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"Call the closure on the error of `err`, the result is wrapped into `err` again, `ok` stays unchanged.
+
+The error is passed as both the parameter and the pipeline input of the closure. Errors from `fp try` are records of `{msg, labels: [{text, span}], help, span}`, `fp unwrap` raises an error with the same fields if they are kept."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["result", "error", "transform"]
+    }
+
+    fn run(
+        &self,
+        plugin: &Self::Plugin,
+        engine: &nu_plugin::EngineInterface,
+        call: &nu_plugin::EvaluatedCall,
+        input: &Value,
+    ) -> Result<Value, LabeledError> {
+        let function = FunctionValue::from_callable(call.req(0)?)?;
+        let result = match ResultValue::from_value(input)? {
+            ok @ ResultValue::Ok(_) => ok.clone(),
+            ResultValue::Err(error) => ResultValue::Err(function.call(
+                plugin,
+                engine,
+                vec![error.clone()],
+                Some(error.clone()),
+            )?),
+        };
+
+        Ok(result.into_value(call.head))
+    }
+
+    fn examples(&self) -> Vec<nu_protocol::Example<'_>> {
+        vec![Example {
+            description: "Raise the error with another message",
+            example: "'a' | fp try { into int } | fp map-err {|e| $e | update msg 'Not a number'} | fp unwrap",
+            result: None,
+        }]
+    }
+}
@@ -0,0 +1,75 @@
+use nu_plugin::SimplePluginCommand;
+use nu_protocol::{Category, Example, LabeledError, Signature, SyntaxShape, Type, Value};
+
+use crate::{
+    FpPlugin,
+    values::{FunctionValue, ResultValue},
+};
+
+#[derive(Clone)]
+pub struct MapOk;
+
+impl SimplePluginCommand for MapOk {
+    type Plugin = FpPlugin;
+
+    fn name(&self) -> &str {
+        "fp map-ok"
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build(self.name())
+            .input_output_type(Type::Custom("result".into()), Type::Custom("result".into()))
+            .required(
+                "closure",
+                SyntaxShape::OneOf(vec![
+                    SyntaxShape::Closure(Some(vec![SyntaxShape::Any])),
+                    SyntaxShape::Any,
+                ]),
+                "The closure to transform the value of `ok`.",
+            )
+            .category(Category::Custom("functional".into()))
+    }
+
+    fn description(&self) -> &str {
+        "Transform the value of an `ok` result."
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"Call the closure on the value of `ok`, the result is wrapped into `ok` again, `err` stays unchanged.
+
+The value is passed as both the parameter and the pipeline input of the closure."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["result", "transform"]
+    }
+
+    fn run(
+        &self,
+        plugin: &Self::Plugin,
+        engine: &nu_plugin::EngineInterface,
+        call: &nu_plugin::EvaluatedCall,
+        input: &Value,
+    ) -> Result<Value, LabeledError> {
+        let function = FunctionValue::from_callable(call.req(0)?)?;
+        let result = match ResultValue::from_value(input)? {
+            ResultValue::Ok(value) => ResultValue::Ok(function.call(
+                plugin,
+                engine,
+                vec![value.clone()],
+                Some(value.clone()),
+            )?),
+            err @ ResultValue::Err(_) => err.clone(),
+        };
+
+        Ok(result.into_value(call.head))
+    }
+
+    fn examples(&self) -> Vec<nu_protocol::Example<'_>> {
+        vec![Example {
+            description: "Double a parsed number",
+            example: "'2' | fp try { into int } | fp map-ok {|x| $x * 2} | fp unwrap",
+            result: Some(Value::test_int(4)),
+        }]
+    }
+}
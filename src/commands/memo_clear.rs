@@ -0,0 +1,71 @@
+use nu_plugin::SimplePluginCommand;
+use nu_protocol::{Category, Example, LabeledError, Signature, SyntaxShape, Type, Value};
+
+use crate::{FpPlugin, values::FunctionValue};
+
+#[derive(Clone)]
+pub struct MemoClear;
+
+impl SimplePluginCommand for MemoClear {
+    type Plugin = FpPlugin;
+
+    fn name(&self) -> &str {
+        "fp memo-clear"
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build(self.name())
+            .input_output_type(Type::Nothing, Type::Nothing)
+            .optional(
+                "function",
+                SyntaxShape::Any,
+                "The memoized function to clear cached results of, clear all if not given.",
+            )
+            .category(Category::Custom("functional".into()))
+    }
+
+    fn description(&self) -> &str {
+        "Clear cached results of memoized functions."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["cache", "memo", "memoize", "reset"]
+    }
+
+    fn run(
+        &self,
+        plugin: &Self::Plugin,
+        _engine: &nu_plugin::EngineInterface,
+        call: &nu_plugin::EvaluatedCall,
+        _input: &Value,
+    ) -> Result<Value, LabeledError> {
+        let id = match call.opt::<Value>(0)? {
+            None => None,
+            Some(value) => match FunctionValue::try_from_value(&value) {
+                Some(FunctionValue::Memoized { id, .. }) => Some(*id),
+                _ => {
+                    return Err(LabeledError::new("Not a memoized function")
+                        .with_label("expected a function returned by `fp memoize`", value.span()));
+                }
+            },
+        };
+        plugin.memo().clear(id);
+
+        Ok(Value::nothing(call.head))
+    }
+
+    fn examples(&self) -> Vec<nu_protocol::Example<'_>> {
+        vec![
+            Example {
+                description: "Clear all cached results",
+                example: "fp memo-clear",
+                result: None,
+            },
+            Example {
+                description: "Clear cached results of a memoized function",
+                example: "let f = fp memoize {|x| $x * 2}; fp memo-clear $f",
+                result: None,
+            },
+        ]
+    }
+}
@@ -0,0 +1,66 @@
+use nu_plugin::SimplePluginCommand;
+use nu_protocol::{Category, Example, LabeledError, Signature, Type, Value, record};
+
+use crate::FpPlugin;
+
+#[derive(Clone)]
+pub struct MemoStats;
+
+impl SimplePluginCommand for MemoStats {
+    type Plugin = FpPlugin;
+
+    fn name(&self) -> &str {
+        "fp memo-stats"
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build(self.name())
+            .input_output_type(Type::Nothing, Type::record())
+            .category(Category::Custom("functional".into()))
+    }
+
+    fn description(&self) -> &str {
+        "Show statistics of cached results of memoized functions."
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"Returns a record of:
+
+* functions: number of memoized functions having cached results
+* entries: number of cached results
+* hits: number of calls returning a cached result
+* misses: number of calls not cached before"#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["cache", "memo", "memoize", "statistics"]
+    }
+
+    fn run(
+        &self,
+        plugin: &Self::Plugin,
+        _engine: &nu_plugin::EngineInterface,
+        call: &nu_plugin::EvaluatedCall,
+        _input: &Value,
+    ) -> Result<Value, LabeledError> {
+        let stats = plugin.memo().stats();
+
+        Ok(Value::record(
+            record! {
+                "functions" => Value::int(stats.functions as i64, call.head),
+                "entries" => Value::int(stats.entries as i64, call.head),
+                "hits" => Value::int(stats.hits as i64, call.head),
+                "misses" => Value::int(stats.misses as i64, call.head),
+            },
+            call.head,
+        ))
+    }
+
+    fn examples(&self) -> Vec<nu_protocol::Example<'_>> {
+        vec![Example {
+            description: "Show statistics of the cache",
+            example: "fp memo-stats",
+            result: None,
+        }]
+    }
+}
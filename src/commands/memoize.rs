@@ -0,0 +1,73 @@
+use nu_plugin::SimplePluginCommand;
+use nu_protocol::{Category, Example, LabeledError, Signature, SyntaxShape, Type, Value};
+
+use crate::{FpPlugin, config::MemoizeConfig, values::FunctionValue};
+
+#[derive(Clone)]
+pub struct Memoize;
+
+impl SimplePluginCommand for Memoize {
+    type Plugin = FpPlugin;
+
+    fn name(&self) -> &str {
+        "fp memoize"
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build(self.name())
+            .input_output_type(Type::Nothing, Type::Custom("function".into()))
+            .required(
+                "function",
+                SyntaxShape::OneOf(vec![SyntaxShape::Closure(None), SyntaxShape::Any]),
+                "The closure (or function) to cache results of.",
+            )
+            .category(Category::Custom("functional".into()))
+    }
+
+    fn description(&self) -> &str {
+        "Cache the results of a closure by its arguments."
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"Returns a function which calls the closure (or function) only the first time it is called with some arguments, later calls with the same arguments return the cached result. The pipeline input is not part of the cache key, `fp call $f 5` and `5 | fp call $f` share the result.
+
+The cache is kept in the plugin, the plugin is not stopped by garbage collection after using this command so the cache lasts for the shell session. Use `fp memo-stats` to inspect the cache and `fp memo-clear` to clear it.
+
+The number of cached results of each memoized function can be limited with `$env.config.plugins.functional.memoize.max_entries`, the oldest results of the function are dropped first. The limit is read when `fp memoize` runs."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["function", "closure", "cache", "memo"]
+    }
+
+    fn run(
+        &self,
+        plugin: &Self::Plugin,
+        engine: &nu_plugin::EngineInterface,
+        call: &nu_plugin::EvaluatedCall,
+        _input: &Value,
+    ) -> Result<Value, LabeledError> {
+        let function = FunctionValue::from_callable(call.req(0)?)?;
+        let max_entries = plugin.config::<MemoizeConfig>(engine)?.max_entries;
+        engine.set_gc_disabled(true)?;
+
+        Ok(FunctionValue::Memoized {
+            id: plugin.memo().new_id(),
+            function: Box::new(function),
+            max_entries,
+        }
+        .into_value(call.head))
+    }
+
+    fn examples(&self) -> Vec<nu_protocol::Example<'_>> {
+        vec![Example {
+            description: "Cache the results of an expensive closure",
+            example: "let f = fp memoize {|x| sleep 1sec; $x * 2}; [1, 1, 1] | each {|x| fp call $f $x}",
+            result: Some(Value::test_list(vec![
+                Value::test_int(2),
+                Value::test_int(2),
+                Value::test_int(2),
+            ])),
+        }]
+    }
+}
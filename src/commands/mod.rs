@@ -3,17 +3,90 @@ use nu_protocol::{Category, Signature, Value};
 
 use crate::FpPlugin;
 
+mod and_then;
+mod assert_type;
+mod bind;
+mod break_;
+mod call;
+mod chunk_by;
+mod coalesce;
+mod compose;
+mod condition;
+mod drop_while;
+mod find_index;
+mod find_last_index;
 mod first_where;
+mod fold;
 mod is;
+mod is_ok;
+mod iterate;
+mod last_where;
+mod map;
+mod map_err;
+mod map_ok;
+mod memo_clear;
+mod memo_stats;
+mod memoize;
+mod none;
 mod other_;
+mod partial;
+mod partition;
+mod pipe;
 mod pure;
+mod scan;
+mod some;
+mod span;
+mod take_while;
 mod then;
+mod to_nullable;
+mod try_;
+mod unfold;
+mod unwrap;
+mod unwrap_or;
+mod unzip;
+mod zip_with;
 
+pub use and_then::AndThen;
+pub use assert_type::AssertType;
+pub use bind::Bind;
+pub use break_::Break;
+pub use call::Call;
+pub use chunk_by::ChunkBy;
+pub use coalesce::Coalesce;
+pub use compose::Compose;
+pub use drop_while::DropWhile;
+pub use find_index::FindIndex;
+pub use find_last_index::FindLastIndex;
 pub use first_where::FirstWhere;
+pub use fold::Fold;
 pub use is::Is;
+pub use is_ok::IsOk;
+pub use iterate::Iterate;
+pub use last_where::LastWhere;
+pub use map::Map;
+pub use map_err::MapErr;
+pub use map_ok::MapOk;
+pub use memo_clear::MemoClear;
+pub use memo_stats::MemoStats;
+pub use memoize::Memoize;
+pub use none::None_;
 pub use other_::Other;
+pub use partial::Partial;
+pub use partition::Partition;
+pub use pipe::Pipe;
 pub use pure::Pure;
+pub use scan::Scan;
+pub use some::Some_;
+pub use span::SpanCmd;
+pub use take_while::TakeWhile;
 pub use then::Then;
+pub use to_nullable::ToNullable;
+pub use try_::Try;
+pub use unfold::Unfold;
+pub use unwrap::Unwrap;
+pub use unwrap_or::UnwrapOr;
+pub use unzip::Unzip;
+pub use zip_with::ZipWith;
 
 pub struct Main;
 
@@ -37,10 +110,46 @@ impl SimplePluginCommand for Main {
 
 Provided commands:
 
+* and-then
+* assert-type
+* bind
+* break
+* call
+* chunk-by
+* coalesce
+* compose
+* drop-while
+* find-index
+* find-last-index
 * first-where
+* fold
 * is
+* is-ok
+* iterate
+* last-where
+* map
+* map-err
+* map-ok
+* memo-clear
+* memo-stats
+* memoize
+* none
 * other
-* then"#
+* partial
+* partition
+* pipe
+* scan
+* some
+* span
+* take-while
+* then
+* to-nullable
+* try
+* unfold
+* unwrap
+* unwrap-or
+* unzip
+* zip-with"#
     }
 
     fn search_terms(&self) -> Vec<&str> {
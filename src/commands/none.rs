@@ -0,0 +1,47 @@
+use nu_plugin::SimplePluginCommand;
+use nu_protocol::{Category, Example, LabeledError, Signature, Type, Value};
+
+use crate::{FpPlugin, values::MaybeValue};
+
+#[derive(Clone)]
+pub struct None_;
+
+impl SimplePluginCommand for None_ {
+    type Plugin = FpPlugin;
+
+    fn name(&self) -> &str {
+        "fp none"
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build(self.name())
+            .input_output_type(Type::Nothing, Type::Custom("maybe".into()))
+            .category(Category::Custom("functional".into()))
+    }
+
+    fn description(&self) -> &str {
+        "An absent maybe value."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["maybe", "option", "optional", "nothing"]
+    }
+
+    fn run(
+        &self,
+        _plugin: &Self::Plugin,
+        _engine: &nu_plugin::EngineInterface,
+        call: &nu_plugin::EvaluatedCall,
+        _input: &Value,
+    ) -> Result<Value, LabeledError> {
+        Ok(MaybeValue::None.into_value(call.head))
+    }
+
+    fn examples(&self) -> Vec<nu_protocol::Example<'_>> {
+        vec![Example {
+            description: "Use a fallback for an absent value",
+            example: "fp none | fp unwrap-or 1",
+            result: Some(Value::test_int(1)),
+        }]
+    }
+}
@@ -1,12 +1,20 @@
-use nu_plugin::SimplePluginCommand;
-use nu_protocol::{Category, Example, Signature, Spanned, SyntaxShape, Type, Value, engine};
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    Category, Example, LabeledError, PipelineData, Signature, Span, SyntaxShape, Type, Value,
+};
 
-use crate::FpPlugin;
+use crate::{
+    FpPlugin,
+    config::PureConfig,
+    values::{FunctionValue, MaybeValue},
+};
+
+use super::{Pure, then::map_each};
 
 #[derive(Clone)]
 pub struct Other;
 
-impl SimplePluginCommand for Other {
+impl PluginCommand for Other {
     type Plugin = FpPlugin;
 
     fn name(&self) -> &str {
@@ -15,12 +23,28 @@ impl SimplePluginCommand for Other {
 
     fn signature(&self) -> nu_protocol::Signature {
         Signature::build(self.name())
-            .input_output_type(Type::Any, Type::Any)
+            .input_output_types(vec![
+                (Type::Any, Type::Any),
+                (
+                    Type::List(Box::new(Type::Any)),
+                    Type::List(Box::new(Type::Any)),
+                ),
+            ])
             .required(
                 "value",
                 SyntaxShape::Any,
                 "The value (or how to produce the value) to use when input is `null`",
             )
+            .switch(
+                "each",
+                "Replace each element of a list or stream which is `null`",
+                Some('e'),
+            )
+            .switch(
+                "pure",
+                "Also treat values which `fp pure` converts to `null` as absent",
+                Some('p'),
+            )
             .category(Category::Conversions)
     }
 
@@ -31,7 +55,13 @@ impl SimplePluginCommand for Other {
     fn extra_description(&self) -> &str {
         r#"Use another value to continue the pipeline if input is `null`.
 
-The value can be a direct value, or a closure-like statement that produces that value."#
+The value can be a direct value, or a closure-like statement that produces that value.
+
+Input can also be a maybe value: the value of `fp some <value>` is used even if it is `null`, `fp none` uses the other value.
+
+With `--each`, each element of the input (e.g. a list or a stream) is checked instead of the whole input, `null` elements are replaced and error elements are passed through. The result is a stream, so upstream elements are processed lazily. Other input is checked as a single value, like without `--each`.
+
+With `--pure`, empty values like `0`, `''` and `[]` are treated as absent too, following the rules of `fp pure`, and a closure receives the original value as input."#
     }
 
     fn search_terms(&self) -> Vec<&str> {
@@ -40,20 +70,29 @@ The value can be a direct value, or a closure-like statement that produces that
 
     fn run(
         &self,
-        _plugin: &Self::Plugin,
-        engine: &nu_plugin::EngineInterface,
-        call: &nu_plugin::EvaluatedCall,
-        input: &nu_protocol::Value,
-    ) -> Result<nu_protocol::Value, nu_protocol::LabeledError> {
-        if input.is_nothing() {
-            if let Ok(c) = call.req::<Spanned<engine::Closure>>(0) {
-                Ok(engine.eval_closure(&c, vec![], Some(input.clone()))?)
-            } else {
-                Ok(call.req(0)?)
-            }
+        plugin: &Self::Plugin,
+        engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let fallback: Value = call.req(0)?;
+        let head = call.head;
+        let pure = if call.has_flag("pure")? {
+            Some(plugin.config::<PureConfig>(engine)?)
         } else {
-            Ok(input.clone())
+            None
+        };
+        if call.has_flag("each")? {
+            return map_each(plugin, engine, head, input, move |plugin, engine, input| {
+                other(plugin, engine, fallback.clone(), input, pure.as_ref(), head)
+            });
         }
+
+        let input = input.into_value(head)?;
+        Ok(PipelineData::Value(
+            other(plugin, engine, fallback, input, pure.as_ref(), head)?,
+            None,
+        ))
     }
 
     fn examples(&self) -> Vec<nu_protocol::Example<'_>> {
@@ -78,6 +117,83 @@ The value can be a direct value, or a closure-like statement that produces that
                 example: "[1, 2, 4, 8] | fp first-where $it > 5 | fp other 100",
                 result: Some(Value::test_int(8)),
             },
+            Example {
+                description: "Replace null elements with 0",
+                example: "[1, null, 3] | fp other --each 0",
+                result: Some(Value::test_list(vec![
+                    Value::test_int(1),
+                    Value::test_int(0),
+                    Value::test_int(3),
+                ])),
+            },
+            Example {
+                description: "Replace null with 0, input is not a list",
+                example: "null | fp other --each 0",
+                result: Some(Value::test_int(0)),
+            },
+            Example {
+                description: "Use \"foo\" if input is empty",
+                example: "'' | fp other --pure foo",
+                result: Some(Value::test_string("foo")),
+            },
+            Example {
+                description: "Use \"foo\" if input is absent, a present null is kept",
+                example: "fp some null | fp other foo",
+                result: Some(Value::test_nothing()),
+            },
         ]
     }
 }
+
+/// Use `input` if it is present, otherwise produce the fallback value.
+///
+/// With `pure`, values `fp pure` converts to `null` are absent too, the fallback closure receives
+/// the original value.
+fn other(
+    plugin: &FpPlugin,
+    engine: &EngineInterface,
+    fallback: Value,
+    input: Value,
+    pure: Option<&PureConfig>,
+    span: Span,
+) -> Result<Value, LabeledError> {
+    match present(&input, pure) {
+        Some(value) => Ok(value.clone()),
+        None if MaybeValue::try_from_value(&input).is_some() => {
+            eval_fallback(plugin, engine, fallback, &Value::nothing(span))
+        }
+        None => eval_fallback(plugin, engine, fallback, &input),
+    }
+}
+
+/// Get the present value of `value`, the value of `fp some` is unwrapped.
+///
+/// `null` and `fp none` are absent, with `pure`, values `fp pure` converts to `null` are absent too.
+pub(super) fn present<'a>(value: &'a Value, pure: Option<&PureConfig>) -> Option<&'a Value> {
+    match MaybeValue::try_from_value(value) {
+        Some(MaybeValue::Some(value)) => Some(value),
+        Some(MaybeValue::None) => None,
+        None => match pure {
+            Some(config) if Pure::is_empty(value, config) => None,
+            None if value.is_nothing() => None,
+            _ => Some(value),
+        },
+    }
+}
+
+/// Produce the fallback value for `input`.
+///
+/// If `fallback` is a closure or function, it is called with `input` as pipeline input, otherwise
+/// `fallback` itself is used.
+pub(super) fn eval_fallback(
+    plugin: &FpPlugin,
+    engine: &EngineInterface,
+    fallback: Value,
+    input: &Value,
+) -> Result<Value, LabeledError> {
+    if FunctionValue::is_callable(&fallback) {
+        FunctionValue::from_callable(fallback)?.call(plugin, engine, vec![], Some(input.clone()))
+    } else {
+        Ok(fallback)
+    }
+}
@@ -0,0 +1,78 @@
+use nu_plugin::SimplePluginCommand;
+use nu_protocol::{Category, Example, LabeledError, Signature, SyntaxShape, Type, Value};
+
+use crate::{FpPlugin, values::FunctionValue};
+
+#[derive(Clone)]
+pub struct Partial;
+
+impl SimplePluginCommand for Partial {
+    type Plugin = FpPlugin;
+
+    fn name(&self) -> &str {
+        "fp partial"
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build(self.name())
+            .input_output_type(Type::Nothing, Type::Custom("function".into()))
+            .required(
+                "function",
+                SyntaxShape::OneOf(vec![SyntaxShape::Closure(None), SyntaxShape::Any]),
+                "The closure (or function) to bind arguments to.",
+            )
+            .rest("args", SyntaxShape::Any, "The leading arguments to bind.")
+            .category(Category::Custom("functional".into()))
+    }
+
+    fn description(&self) -> &str {
+        "Bind leading arguments of a closure into a function."
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"Returns a function which calls the closure (or function) with the bound arguments followed by the arguments of each call.
+
+Functions can be called with `fp call`, and used in place of closures in commands like `fp first-where`, `fp then` and `fp other`."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["function", "closure", "curry", "bind", "apply"]
+    }
+
+    fn run(
+        &self,
+        _plugin: &Self::Plugin,
+        _engine: &nu_plugin::EngineInterface,
+        call: &nu_plugin::EvaluatedCall,
+        _input: &Value,
+    ) -> Result<Value, LabeledError> {
+        let function = FunctionValue::from_callable(call.req(0)?)?;
+        let args = call.rest::<Value>(1)?;
+
+        Ok(FunctionValue::Partial {
+            function: Box::new(function),
+            args,
+        }
+        .into_value(call.head))
+    }
+
+    fn examples(&self) -> Vec<nu_protocol::Example<'_>> {
+        vec![
+            Example {
+                description: "Bind the first two arguments of a closure",
+                example: "let f = fp partial {|a, b, c| $a + $b + $c} 1 2; fp call $f 3",
+                result: Some(Value::test_int(6)),
+            },
+            Example {
+                description: "Use a pre-configured predicate in first-where",
+                example: "let larger_than = fp partial {|n, x| $x > $n} 5; [1, 2, 4, 8] | fp first-where $larger_than",
+                result: Some(Value::test_int(8)),
+            },
+            Example {
+                description: "Use a pre-configured callback in then",
+                example: "let add = fp partial {|a, b| $a + $b} 10; 1 | fp then $add",
+                result: Some(Value::test_int(11)),
+            },
+        ]
+    }
+}
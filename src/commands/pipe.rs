@@ -0,0 +1,76 @@
+use nu_plugin::SimplePluginCommand;
+use nu_protocol::{Category, Example, LabeledError, Signature, SyntaxShape, Type, Value};
+
+use crate::{FpPlugin, values::FunctionValue};
+
+#[derive(Clone)]
+pub struct Pipe;
+
+impl SimplePluginCommand for Pipe {
+    type Plugin = FpPlugin;
+
+    fn name(&self) -> &str {
+        "fp pipe"
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build(self.name())
+            .input_output_type(Type::Any, Type::Any)
+            .rest(
+                "functions",
+                SyntaxShape::OneOf(vec![SyntaxShape::Closure(None), SyntaxShape::Any]),
+                "The closures or functions to call in order.",
+            )
+            .category(Category::Custom("functional".into()))
+    }
+
+    fn description(&self) -> &str {
+        "Call closures from left to right on the input."
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"Call the first closure (or function) with the input, then call the next one with the output of the previous one, returns the output of the last one.
+
+Each closure receives the value both as the first parameter and as pipeline input."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["function", "closure", "chain", "point-free"]
+    }
+
+    fn run(
+        &self,
+        plugin: &Self::Plugin,
+        engine: &nu_plugin::EngineInterface,
+        call: &nu_plugin::EvaluatedCall,
+        input: &Value,
+    ) -> Result<Value, LabeledError> {
+        let functions = call
+            .rest::<Value>(0)?
+            .into_iter()
+            .map(FunctionValue::from_callable)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        FunctionValue::Pipe(functions).call(
+            plugin,
+            engine,
+            vec![input.clone()],
+            Some(input.clone()),
+        )
+    }
+
+    fn examples(&self) -> Vec<nu_protocol::Example<'_>> {
+        vec![
+            Example {
+                description: "Call closures from left to right",
+                example: "5 | fp pipe {|x| $x + 1} {|x| $x * 2}",
+                result: Some(Value::test_int(12)),
+            },
+            Example {
+                description: "Mix composed functions and closures",
+                example: "let f = fp compose {|x| $x + 1} {|x| $x * 2}; 5 | fp pipe $f {$in - 1}",
+                result: Some(Value::test_int(10)),
+            },
+        ]
+    }
+}
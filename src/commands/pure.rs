@@ -1,7 +1,11 @@
 use nu_plugin::SimplePluginCommand;
 use nu_protocol::{Category, Example, Filesize, Signature, Type, Value};
 
-use crate::FpPlugin;
+use crate::{
+    FpPlugin,
+    config::{EmptyType, PureConfig},
+};
+
 #[derive(Clone)]
 pub struct Pure;
 
@@ -38,6 +42,10 @@ impl SimplePluginCommand for Pure {
 
 These values will be converted to `null`.
 
+Which types are checked can be configured with `$env.config.plugins.functional.pure.empty`, a list
+of type names from `int`, `float`, `string`, `bool`, `duration`, `filesize`, `list`, `record` and
+`table`, all of them by default.
+
 Types not supported:
 
 * date
@@ -53,103 +61,15 @@ Types not supported:
 
     fn run(
         &self,
-        _plugin: &Self::Plugin,
-        _engine: &nu_plugin::EngineInterface,
+        plugin: &Self::Plugin,
+        engine: &nu_plugin::EngineInterface,
         call: &nu_plugin::EvaluatedCall,
         input: &nu_protocol::Value,
     ) -> Result<nu_protocol::Value, nu_protocol::LabeledError> {
-        match input.get_type() {
-            Type::Int => {
-                if input.as_int().unwrap() == 0 {
-                    Ok(Value::nothing(call.head))
-                } else {
-                    Ok(input.clone())
-                }
-            }
-            Type::Float => {
-                if is_null_float(input.as_float().unwrap()) {
-                    Ok(Value::nothing(call.head))
-                } else {
-                    Ok(input.clone())
-                }
-            }
-            Type::String => {
-                if input.as_str().unwrap() == "" {
-                    Ok(Value::nothing(call.head))
-                } else {
-                    Ok(input.clone())
-                }
-            }
-            Type::Bool => {
-                if !input.as_bool().unwrap() {
-                    Ok(Value::nothing(call.head))
-                } else {
-                    Ok(input.clone())
-                }
-            }
-            Type::Duration => {
-                if input.as_duration().unwrap() == 0i64 {
-                    Ok(Value::nothing(call.head))
-                } else {
-                    Ok(input.clone())
-                }
-            }
-            Type::Filesize => {
-                if input.as_filesize().unwrap() == Filesize::ZERO {
-                    Ok(Value::nothing(call.head))
-                } else {
-                    Ok(input.clone())
-                }
-            }
-            Type::List(_) => {
-                if input.as_list().unwrap().is_empty() {
-                    Ok(Value::nothing(call.head))
-                } else {
-                    Ok(input.clone())
-                }
-            }
-            Type::Record(..) => {
-                if input.as_record().unwrap().is_empty() {
-                    Ok(Value::nothing(call.head))
-                } else {
-                    Ok(input.clone())
-                }
-            }
-            Type::Table(data) => {
-                if data.is_empty() {
-                    Ok(Value::nothing(call.head))
-                } else {
-                    Ok(input.clone())
-                }
-            }
-            Type::Nothing => Ok(input.clone()),
-            Type::Number => {
-                if let Ok(i) = input.as_int() {
-                    if i == 0 {
-                        Ok(Value::nothing(call.head))
-                    } else {
-                        Ok(input.clone())
-                    }
-                } else {
-                    if is_null_float(input.as_float().unwrap()) {
-                        Ok(Value::nothing(call.head))
-                    } else {
-                        Ok(input.clone())
-                    }
-                }
-            }
-
-            Type::Any
-            | Type::Binary
-            | Type::Block
-            | Type::CellPath
-            | Type::Closure
-            | Type::Custom(_)
-            | Type::Date
-            | Type::Error
-            | Type::OneOf(_)
-            | Type::Range
-            | Type::Glob => Ok(input.clone()),
+        if Pure::is_empty(input, &plugin.config(engine)?) {
+            Ok(Value::nothing(call.head))
+        } else {
+            Ok(input.clone())
         }
     }
 
@@ -214,6 +134,48 @@ Types not supported:
     }
 }
 
+impl Pure {
+    /// Check if `value` is the default value of its type, which `fp pure` converts to `null`.
+    ///
+    /// Only types in `config.empty` are checked, `null` itself is always empty.
+    pub(super) fn is_empty(value: &Value, config: &PureConfig) -> bool {
+        let enabled = |ty| config.empty.contains(&ty);
+        match value.get_type() {
+            Type::Int => enabled(EmptyType::Int) && value.as_int().unwrap() == 0,
+            Type::Float => enabled(EmptyType::Float) && is_null_float(value.as_float().unwrap()),
+            Type::String => enabled(EmptyType::String) && value.as_str().unwrap() == "",
+            Type::Bool => enabled(EmptyType::Bool) && !value.as_bool().unwrap(),
+            Type::Duration => enabled(EmptyType::Duration) && value.as_duration().unwrap() == 0i64,
+            Type::Filesize => {
+                enabled(EmptyType::Filesize) && value.as_filesize().unwrap() == Filesize::ZERO
+            }
+            Type::List(_) => enabled(EmptyType::List) && value.as_list().unwrap().is_empty(),
+            Type::Record(..) => enabled(EmptyType::Record) && value.as_record().unwrap().is_empty(),
+            Type::Table(data) => enabled(EmptyType::Table) && data.is_empty(),
+            Type::Nothing => true,
+            Type::Number => {
+                if let Ok(i) = value.as_int() {
+                    enabled(EmptyType::Int) && i == 0
+                } else {
+                    enabled(EmptyType::Float) && is_null_float(value.as_float().unwrap())
+                }
+            }
+
+            Type::Any
+            | Type::Binary
+            | Type::Block
+            | Type::CellPath
+            | Type::Closure
+            | Type::Custom(_)
+            | Type::Date
+            | Type::Error
+            | Type::OneOf(_)
+            | Type::Range
+            | Type::Glob => false,
+        }
+    }
+}
+
 fn is_null_float(value: f64) -> bool {
     value.is_infinite() || value == 0f64
 }
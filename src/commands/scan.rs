@@ -0,0 +1,126 @@
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    Category, Example, LabeledError, ListStream, PipelineData, Signature, SyntaxShape, Type, Value,
+};
+
+use crate::{
+    FpPlugin,
+    values::{BreakValue, FunctionValue},
+};
+
+#[derive(Clone)]
+pub struct Scan;
+
+impl PluginCommand for Scan {
+    type Plugin = FpPlugin;
+
+    fn name(&self) -> &str {
+        "fp scan"
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![
+                (
+                    Type::List(Box::new(Type::Any)),
+                    Type::List(Box::new(Type::Any)),
+                ),
+                (Type::table(), Type::List(Box::new(Type::Any))),
+                (Type::Range, Type::List(Box::new(Type::Any))),
+            ])
+            .required("init", SyntaxShape::Any, "The initial accumulator.")
+            .required(
+                "closure",
+                SyntaxShape::OneOf(vec![
+                    SyntaxShape::Closure(Some(vec![SyntaxShape::Any, SyntaxShape::Any])),
+                    SyntaxShape::Any,
+                ]),
+                "The closure to combine the accumulator and an element into the next accumulator.",
+            )
+            .category(Category::Filters)
+    }
+
+    fn description(&self) -> &str {
+        "Produce every intermediate accumulator when combining elements."
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"Like `fp fold`, call the closure `{|acc, x| ...}` on each element with the accumulator, but output the accumulator after each element as a stream instead of only the final one.
+
+The initial accumulator is not in the output. The output is produced lazily, so it works on infinite input and composes with commands like `first` and `fp first-where`.
+
+Return `fp break <value>` from the closure to output the value and stop."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["running", "cumulative", "accumulate", "prefix-sum", "scanl"]
+    }
+
+    fn run(
+        &self,
+        plugin: &Self::Plugin,
+        engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let mut acc: Option<Value> = Some(call.req(0)?);
+        let function = FunctionValue::from_callable(call.req(1)?)?;
+        let signals = engine.signals().clone();
+        let plugin = plugin.clone();
+        let engine = engine.clone();
+        let head = call.head;
+
+        let mut elements = input.into_iter();
+        // The accumulator is left empty after a break or an error to stop the stream.
+        let stream = std::iter::from_fn(move || {
+            let prev = acc.take()?;
+            let value = elements.next()?;
+            match function.call(&plugin, &engine, vec![prev.clone(), value], Some(prev)) {
+                Ok(next) => match BreakValue::try_from_value(&next) {
+                    Some(value) => Some(value.clone()),
+                    None => {
+                        acc = Some(next.clone());
+                        Some(next)
+                    }
+                },
+                Err(error) => Some(Value::error(error.into(), head)),
+            }
+        });
+
+        Ok(PipelineData::ListStream(
+            ListStream::new(stream, head, signals),
+            None,
+        ))
+    }
+
+    fn examples(&self) -> Vec<nu_protocol::Example<'_>> {
+        vec![
+            Example {
+                description: "Running total of a list",
+                example: "[1, 2, 3, 4] | fp scan 0 {|acc, x| $acc + $x}",
+                result: Some(Value::test_list(vec![
+                    Value::test_int(1),
+                    Value::test_int(3),
+                    Value::test_int(6),
+                    Value::test_int(10),
+                ])),
+            },
+            Example {
+                description: "Cumulative maximum of a list",
+                example: "[3, 1, 4, 1, 5] | fp scan 0 {|acc, x| [$acc, $x] | math max}",
+                result: Some(Value::test_list(vec![
+                    Value::test_int(3),
+                    Value::test_int(3),
+                    Value::test_int(4),
+                    Value::test_int(4),
+                    Value::test_int(5),
+                ])),
+            },
+            Example {
+                description: "The first running total larger than 100 of an infinite range",
+                example: "1.. | fp scan 0 {|acc, x| $acc + $x} | fp first-where $it > 100",
+                result: Some(Value::test_int(105)),
+            },
+        ]
+    }
+}
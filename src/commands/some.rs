@@ -0,0 +1,71 @@
+use nu_plugin::SimplePluginCommand;
+use nu_protocol::{Category, Example, LabeledError, Signature, SyntaxShape, Type, Value};
+
+use crate::{FpPlugin, values::MaybeValue};
+
+#[derive(Clone)]
+pub struct Some_;
+
+impl SimplePluginCommand for Some_ {
+    type Plugin = FpPlugin;
+
+    fn name(&self) -> &str {
+        "fp some"
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build(self.name())
+            .input_output_type(Type::Any, Type::Custom("maybe".into()))
+            .optional(
+                "value",
+                SyntaxShape::Any,
+                "The present value, use the input if not given.",
+            )
+            .category(Category::Custom("functional".into()))
+    }
+
+    fn description(&self) -> &str {
+        "Wrap a value into a present maybe value."
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"A maybe value is either `some` value or `none`, `null` can be present too, e.g. `fp some null`.
+
+Use `fp map` and `fp bind` to transform it, and `fp unwrap-or` or `fp to-nullable` to get the value back."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["maybe", "option", "optional", "just"]
+    }
+
+    fn run(
+        &self,
+        _plugin: &Self::Plugin,
+        _engine: &nu_plugin::EngineInterface,
+        call: &nu_plugin::EvaluatedCall,
+        input: &Value,
+    ) -> Result<Value, LabeledError> {
+        let value = call.opt(0)?.unwrap_or_else(|| input.clone());
+        Ok(MaybeValue::Some(value).into_value(call.head))
+    }
+
+    fn examples(&self) -> Vec<nu_protocol::Example<'_>> {
+        vec![
+            Example {
+                description: "Wrap a value",
+                example: "fp some 1 | fp to-nullable",
+                result: Some(Value::test_int(1)),
+            },
+            Example {
+                description: "Wrap the input",
+                example: "1 | fp some | fp to-nullable",
+                result: Some(Value::test_int(1)),
+            },
+            Example {
+                description: "A present null is not absent",
+                example: "fp some null | fp unwrap-or 1",
+                result: Some(Value::test_nothing()),
+            },
+        ]
+    }
+}
@@ -0,0 +1,90 @@
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    Category, Example, LabeledError, PipelineData, Signature, SyntaxShape, Type, Value, record,
+};
+
+use crate::FpPlugin;
+
+use super::condition::{Condition, elements};
+
+#[derive(Clone)]
+pub struct SpanCmd;
+
+impl PluginCommand for SpanCmd {
+    type Plugin = FpPlugin;
+
+    fn name(&self) -> &str {
+        "fp span"
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![
+                (Type::List(Box::new(Type::Any)), Type::record()),
+                (Type::table(), Type::record()),
+                (Type::Range, Type::record()),
+            ])
+            .required(
+                "condition",
+                SyntaxShape::RowCondition,
+                "Row condition or closure the elements in head satify.",
+            )
+            .category(Category::Filters)
+    }
+
+    fn description(&self) -> &str {
+        "Split elements at the first element not meeting a condition."
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"Returns a record `{head, tail}`, where `head` is the same as `fp take-while` and `tail` is the same as `fp drop-while`, but the condition is checked only once on each element."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["split", "break", "partition", "condition"]
+    }
+
+    fn run(
+        &self,
+        plugin: &Self::Plugin,
+        engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let condition = Condition::new(call.req(0)?)?;
+
+        let mut head = vec![];
+        let mut tail = vec![];
+        for (index, value) in elements(input).enumerate() {
+            let value = value?;
+            engine.signals().check(&call.head)?;
+            if tail.is_empty() && condition.meets(plugin, engine, index, &value)? {
+                head.push(value);
+            } else {
+                tail.push(value);
+            }
+        }
+
+        Ok(PipelineData::Value(
+            Value::record(
+                record! {
+                    "head" => Value::list(head, call.head),
+                    "tail" => Value::list(tail, call.head),
+                },
+                call.head,
+            ),
+            None,
+        ))
+    }
+
+    fn examples(&self) -> Vec<nu_protocol::Example<'_>> {
+        vec![Example {
+            description: "Split a list at the first element not smaller than 5",
+            example: "[1, 2, 8, 4] | fp span $it < 5",
+            result: Some(Value::test_record(record! {
+                "head" => Value::test_list(vec![Value::test_int(1), Value::test_int(2)]),
+                "tail" => Value::test_list(vec![Value::test_int(8), Value::test_int(4)]),
+            })),
+        }]
+    }
+}
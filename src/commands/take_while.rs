@@ -0,0 +1,114 @@
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    Category, Example, LabeledError, ListStream, PipelineData, Signature, SyntaxShape, Type, Value,
+};
+
+use crate::FpPlugin;
+
+use super::condition::{Condition, elements};
+
+#[derive(Clone)]
+pub struct TakeWhile;
+
+impl PluginCommand for TakeWhile {
+    type Plugin = FpPlugin;
+
+    fn name(&self) -> &str {
+        "fp take-while"
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![
+                (
+                    Type::List(Box::new(Type::Any)),
+                    Type::List(Box::new(Type::Any)),
+                ),
+                (Type::table(), Type::table()),
+                (Type::Range, Type::List(Box::new(Type::Any))),
+            ])
+            .required(
+                "condition",
+                SyntaxShape::RowCondition,
+                "Row condition or closure the taken elements satify.",
+            )
+            .category(Category::Filters)
+    }
+
+    fn description(&self) -> &str {
+        "Take elements while they meet a condition."
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"Output elements from the start of input until the first element not meeting the condition.
+
+The output is produced lazily, so it works on infinite input, and the input is dropped once an element does not meet the condition."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["prefix", "first", "condition"]
+    }
+
+    fn run(
+        &self,
+        plugin: &Self::Plugin,
+        engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let condition = Condition::new(call.req(0)?)?;
+        let signals = engine.signals().clone();
+        let plugin = plugin.clone();
+        let engine = engine.clone();
+        let head = call.head;
+
+        let mut done = false;
+        let stream = elements(input)
+            .enumerate()
+            .map_while(move |(index, value)| {
+                if done {
+                    return None;
+                }
+                let result = value.and_then(|value| {
+                    condition
+                        .meets(&plugin, &engine, index, &value)
+                        .map(|meets| meets.then_some(value))
+                });
+                match result {
+                    Ok(value) => value,
+                    Err(error) => {
+                        done = true;
+                        Some(Value::error(error.into(), head))
+                    }
+                }
+            });
+
+        Ok(PipelineData::ListStream(
+            ListStream::new(stream, head, signals),
+            None,
+        ))
+    }
+
+    fn examples(&self) -> Vec<nu_protocol::Example<'_>> {
+        vec![
+            Example {
+                description: "Take elements while they are smaller than 5",
+                example: "[1, 2, 8, 4] | fp take-while $it < 5",
+                result: Some(Value::test_list(vec![
+                    Value::test_int(1),
+                    Value::test_int(2),
+                ])),
+            },
+            Example {
+                description: "Take elements while they are smaller than 5, using closure as condition",
+                example: "1.. | fp take-while {|x| $x < 5}",
+                result: Some(Value::test_list(vec![
+                    Value::test_int(1),
+                    Value::test_int(2),
+                    Value::test_int(3),
+                    Value::test_int(4),
+                ])),
+            },
+        ]
+    }
+}
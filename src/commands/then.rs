@@ -1,14 +1,17 @@
-use nu_plugin::SimplePluginCommand;
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
 use nu_protocol::{
-    Category, Example, Signature, Spanned, SyntaxShape, Type, Value, engine::Closure,
+    Category, Example, LabeledError, ListStream, PipelineData, Signature, Span, SyntaxShape, Type,
+    Value,
 };
 
-use crate::FpPlugin;
+use crate::{FpPlugin, config::PureConfig, values::FunctionValue};
+
+use super::{condition::elements, other_::present};
 
 #[derive(Clone)]
 pub struct Then;
 
-impl SimplePluginCommand for Then {
+impl PluginCommand for Then {
     type Plugin = FpPlugin;
 
     fn name(&self) -> &str {
@@ -17,7 +20,13 @@ impl SimplePluginCommand for Then {
 
     fn signature(&self) -> nu_protocol::Signature {
         Signature::build(self.name())
-            .input_output_type(Type::Any, Type::Any)
+            .input_output_types(vec![
+                (Type::Any, Type::Any),
+                (
+                    Type::List(Box::new(Type::Any)),
+                    Type::List(Box::new(Type::Any)),
+                ),
+            ])
             .required(
                 "value",
                 SyntaxShape::OneOf(vec![
@@ -27,6 +36,16 @@ impl SimplePluginCommand for Then {
                 ]),
                 "The value (or how to produce the value) to use when input is not `null`.",
             )
+            .switch(
+                "each",
+                "Do something with each element of a list or stream which is not `null`",
+                Some('e'),
+            )
+            .switch(
+                "pure",
+                "Also treat values which `fp pure` converts to `null` as absent",
+                Some('p'),
+            )
             .category(Category::Conversions)
     }
 
@@ -35,7 +54,13 @@ impl SimplePluginCommand for Then {
     }
 
     fn extra_description(&self) -> &str {
-        r#"Do something (e.g. eval a closure) when input is not `null`, return `null` if input is `null`."#
+        r#"Do something (e.g. eval a closure) when input is not `null`, return `null` if input is `null`.
+
+Input can also be a maybe value: `fp some <value>` is present even if the value is `null`, and the value is used as input, `fp none` is absent.
+
+With `--each`, each element of the input (e.g. a list or a stream) is checked instead of the whole input, `null` elements stay `null` and error elements are passed through. The result is a stream, so upstream elements are processed lazily. Other input is checked as a single value, like without `--each`.
+
+With `--pure`, empty values like `0`, `''` and `[]` are treated as absent too, following the rules of `fp pure`, but unlike `fp pure | fp then`, the closure still receives the original value."#
     }
 
     fn search_terms(&self) -> Vec<&str> {
@@ -44,26 +69,29 @@ impl SimplePluginCommand for Then {
 
     fn run(
         &self,
-        _plugin: &Self::Plugin,
-        engine: &nu_plugin::EngineInterface,
-        call: &nu_plugin::EvaluatedCall,
-        input: &nu_protocol::Value,
-    ) -> Result<nu_protocol::Value, nu_protocol::LabeledError> {
-        if input.is_nothing() {
-            Ok(input.clone())
+        plugin: &Self::Plugin,
+        engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let value: Value = call.req(0)?;
+        let head = call.head;
+        let pure = if call.has_flag("pure")? {
+            Some(plugin.config::<PureConfig>(engine)?)
         } else {
-            if let Ok(c) = call.req::<Spanned<Closure>>(0) {
-                // It is safe to always pass the input value to closure, because both:
-                //
-                // 1 | fp then { || $in + 2 }
-                // and
-                // 1 | fp then { |x| $x + 2 }
-                // works.
-                Ok(engine.eval_closure(&c, vec![input.clone()], Some(input.clone()))?)
-            } else {
-                Ok(call.req(0)?)
-            }
+            None
+        };
+        if call.has_flag("each")? {
+            return map_each(plugin, engine, head, input, move |plugin, engine, input| {
+                then(plugin, engine, value.clone(), &input, pure.as_ref(), head)
+            });
         }
+
+        let input = input.into_value(head)?;
+        Ok(PipelineData::Value(
+            then(plugin, engine, value, &input, pure.as_ref(), head)?,
+            None,
+        ))
     }
 
     fn examples(&self) -> Vec<nu_protocol::Example<'_>> {
@@ -71,7 +99,7 @@ impl SimplePluginCommand for Then {
             Example {
                 description: "Use 100 if input is not null",
                 example: "1 | fp then 100",
-                result: Some(Value::test_nothing()),
+                result: Some(Value::test_int(100)),
             },
             Example {
                 description: "Increase the value by 2 if input is not null",
@@ -88,6 +116,116 @@ impl SimplePluginCommand for Then {
                 example: "let foo = 2; 1 | fp then { $foo + 2 }",
                 result: Some(Value::test_int(4)),
             },
+            Example {
+                description: "Increase each element by 2 if it is not null",
+                example: "[1, null, 3] | fp then --each { $in + 2 }",
+                result: Some(Value::test_list(vec![
+                    Value::test_int(3),
+                    Value::test_nothing(),
+                    Value::test_int(5),
+                ])),
+            },
+            Example {
+                description: "Increase the value by 2 if it is not null, input is not a list",
+                example: "1 | fp then --each { $in + 2 }",
+                result: Some(Value::test_int(3)),
+            },
+            Example {
+                description: "Get the length of a string if it is not empty",
+                example: "'' | fp then --pure { str length }",
+                result: Some(Value::test_nothing()),
+            },
+            Example {
+                description: "Increase the value by 2 if input is present",
+                example: "fp some 1 | fp then { $in + 2 }",
+                result: Some(Value::test_int(3)),
+            },
         ]
     }
 }
+
+/// Produce the value from `value` for `input`, or `null` if `input` is absent.
+///
+/// With `pure`, values `fp pure` converts to `null` are absent too.
+fn then(
+    plugin: &FpPlugin,
+    engine: &EngineInterface,
+    value: Value,
+    input: &Value,
+    pure: Option<&PureConfig>,
+    span: Span,
+) -> Result<Value, LabeledError> {
+    if let Some(input) = present(input, pure) {
+        if FunctionValue::is_callable(&value) {
+            // It is safe to always pass the input value to closure, because both:
+            //
+            // 1 | fp then { || $in + 2 }
+            // and
+            // 1 | fp then { |x| $x + 2 }
+            // works.
+            FunctionValue::from_callable(value)?.call(
+                plugin,
+                engine,
+                vec![input.clone()],
+                Some(input.clone()),
+            )
+        } else {
+            Ok(value)
+        }
+    } else {
+        Ok(Value::nothing(span))
+    }
+}
+
+/// Map each element of `input` with `f` into a stream.
+///
+/// Error elements from upstream are passed through unchanged. The stream stops after the first
+/// error returned by `f`, which is emitted as an error value. Input which is not a list, range or
+/// stream is mapped as a single value, so the result keeps its shape.
+pub(super) fn map_each(
+    plugin: &FpPlugin,
+    engine: &EngineInterface,
+    head: Span,
+    input: PipelineData,
+    f: impl Fn(&FpPlugin, &EngineInterface, Value) -> Result<Value, LabeledError> + Send + 'static,
+) -> Result<PipelineData, LabeledError> {
+    let input = match input {
+        PipelineData::Value(value, metadata)
+            if !matches!(value, Value::List { .. } | Value::Range { .. }) =>
+        {
+            let value = match value {
+                Value::Error { .. } => value,
+                value => f(plugin, engine, value)?,
+            };
+            return Ok(PipelineData::Value(value, metadata));
+        }
+        input => input,
+    };
+
+    let signals = engine.signals().clone();
+    let plugin = plugin.clone();
+    let engine = engine.clone();
+
+    let mut done = false;
+    let stream = elements(input).map_while(move |value| {
+        if done {
+            return None;
+        }
+        let result = value.and_then(|value| match value {
+            Value::Error { .. } => Ok(value),
+            value => f(&plugin, &engine, value),
+        });
+        match result {
+            Ok(value) => Some(value),
+            Err(error) => {
+                done = true;
+                Some(Value::error(error.into(), head))
+            }
+        }
+    });
+
+    Ok(PipelineData::ListStream(
+        ListStream::new(stream, head, signals),
+        None,
+    ))
+}
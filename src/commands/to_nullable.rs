@@ -0,0 +1,56 @@
+use nu_plugin::SimplePluginCommand;
+use nu_protocol::{Category, Example, LabeledError, Signature, Type, Value};
+
+use crate::{FpPlugin, values::MaybeValue};
+
+#[derive(Clone)]
+pub struct ToNullable;
+
+impl SimplePluginCommand for ToNullable {
+    type Plugin = FpPlugin;
+
+    fn name(&self) -> &str {
+        "fp to-nullable"
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build(self.name())
+            .input_output_type(Type::Custom("maybe".into()), Type::Any)
+            .category(Category::Custom("functional".into()))
+    }
+
+    fn description(&self) -> &str {
+        "Convert a maybe value to its value, or `null` if it is absent."
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["maybe", "option", "null", "unwrap"]
+    }
+
+    fn run(
+        &self,
+        _plugin: &Self::Plugin,
+        _engine: &nu_plugin::EngineInterface,
+        call: &nu_plugin::EvaluatedCall,
+        input: &Value,
+    ) -> Result<Value, LabeledError> {
+        Ok(MaybeValue::from_value(input)?
+            .clone()
+            .into_nullable(call.head))
+    }
+
+    fn examples(&self) -> Vec<nu_protocol::Example<'_>> {
+        vec![
+            Example {
+                description: "Convert a present value",
+                example: "fp some 1 | fp to-nullable",
+                result: Some(Value::test_int(1)),
+            },
+            Example {
+                description: "Convert an absent value",
+                example: "fp none | fp to-nullable",
+                result: Some(Value::test_nothing()),
+            },
+        ]
+    }
+}
@@ -0,0 +1,84 @@
+use nu_plugin::SimplePluginCommand;
+use nu_protocol::{Category, Example, LabeledError, Signature, SyntaxShape, Type, Value};
+
+use crate::{
+    FpPlugin,
+    values::{FunctionValue, ResultValue},
+};
+
+#[derive(Clone)]
+pub struct Try;
+
+impl SimplePluginCommand for Try {
+    type Plugin = FpPlugin;
+
+    fn name(&self) -> &str {
+        "fp try"
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build(self.name())
+            .input_output_type(Type::Any, Type::Custom("result".into()))
+            .required(
+                "closure",
+                SyntaxShape::OneOf(vec![
+                    SyntaxShape::Closure(Some(vec![SyntaxShape::Any])),
+                    SyntaxShape::Any,
+                ]),
+                "The closure which may fail.",
+            )
+            .category(Category::Custom("functional".into()))
+    }
+
+    fn description(&self) -> &str {
+        "Capture the result or the error of a closure as a value."
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"Call the closure with the input as both the parameter and the pipeline input, returns a result value which is `ok` with the result of the closure, or `err` with the error if the closure fails.
+
+The error is a record of `{msg, labels: [{text, span}], help, span}`.
+
+Use `fp map-ok`, `fp map-err` and `fp and-then` to process the result, `fp is-ok` to check it, and `fp unwrap` to get the value back or raise the error again."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["result", "error", "catch", "fallible"]
+    }
+
+    fn run(
+        &self,
+        plugin: &Self::Plugin,
+        engine: &nu_plugin::EngineInterface,
+        call: &nu_plugin::EvaluatedCall,
+        input: &Value,
+    ) -> Result<Value, LabeledError> {
+        let function = FunctionValue::from_callable(call.req(0)?)?;
+        let outcome = function.call(plugin, engine, vec![input.clone()], Some(input.clone()));
+
+        Ok(ResultValue::from_outcome(outcome, call.head).into_value(call.head))
+    }
+
+    fn examples(&self) -> Vec<nu_protocol::Example<'_>> {
+        vec![
+            Example {
+                description: "Closure succeeding",
+                example: "'1' | fp try {|x| $x | into int} | fp unwrap",
+                result: Some(Value::test_int(1)),
+            },
+            Example {
+                description: "Closure failing",
+                example: "'a' | fp try {|x| $x | into int} | fp is-ok",
+                result: Some(Value::test_bool(false)),
+            },
+            Example {
+                description: "Keep only the strings parsed as numbers",
+                example: "['1', 'a', '3'] | each { fp try { into int } } | where {|r| $r | fp is-ok} | each { fp unwrap }",
+                result: Some(Value::test_list(vec![
+                    Value::test_int(1),
+                    Value::test_int(3),
+                ])),
+            },
+        ]
+    }
+}
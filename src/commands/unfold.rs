@@ -0,0 +1,141 @@
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    Category, Example, LabeledError, ListStream, PipelineData, Signature, SyntaxShape, Type, Value,
+};
+
+use crate::{FpPlugin, values::FunctionValue};
+
+#[derive(Clone)]
+pub struct Unfold;
+
+impl PluginCommand for Unfold {
+    type Plugin = FpPlugin;
+
+    fn name(&self) -> &str {
+        "fp unfold"
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build(self.name())
+            .input_output_type(Type::Nothing, Type::List(Box::new(Type::Any)))
+            .required("seed", SyntaxShape::Any, "The initial state.")
+            .required(
+                "closure",
+                SyntaxShape::OneOf(vec![
+                    SyntaxShape::Closure(Some(vec![SyntaxShape::Any])),
+                    SyntaxShape::Any,
+                ]),
+                "The closure to produce an element and the next state from a state.",
+            )
+            .category(Category::Generators)
+    }
+
+    fn description(&self) -> &str {
+        "Produce a stream from a seed state until the closure returns `null`."
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"Call the closure on the state, which returns either a record `{value, next}` to output `value` and continue with state `next`, or `null` to end the stream.
+
+If `next` is missing in the record, the stream ends after `value`. `null` is a valid state, `next: null` calls the closure on `null` again. The stream is produced lazily."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["generate", "sequence", "stream", "paginate", "anamorphism"]
+    }
+
+    fn run(
+        &self,
+        plugin: &Self::Plugin,
+        engine: &EngineInterface,
+        call: &EvaluatedCall,
+        _input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let mut state: Option<Value> = Some(call.req(0)?);
+        let function = FunctionValue::from_callable(call.req(1)?)?;
+        let signals = engine.signals().clone();
+        let plugin = plugin.clone();
+        let engine = engine.clone();
+        let head = call.head;
+
+        // The state is left empty when the stream ends or after an error.
+        let stream = std::iter::from_fn(move || {
+            let current = state.take()?;
+            let result = step(&plugin, &engine, &function, current);
+            match result {
+                Ok(Some((value, next))) => {
+                    state = next;
+                    Some(value)
+                }
+                Ok(None) => None,
+                Err(error) => Some(Value::error(error.into(), head)),
+            }
+        });
+
+        Ok(PipelineData::ListStream(
+            ListStream::new(stream, head, signals),
+            None,
+        ))
+    }
+
+    fn examples(&self) -> Vec<nu_protocol::Example<'_>> {
+        vec![
+            Example {
+                description: "Count down from 3",
+                example: "fp unfold 3 {|n| if $n > 0 { {value: $n, next: ($n - 1)} } }",
+                result: Some(Value::test_list(vec![
+                    Value::test_int(3),
+                    Value::test_int(2),
+                    Value::test_int(1),
+                ])),
+            },
+            Example {
+                description: "Fibonacci numbers smaller than 20",
+                example: "fp unfold [0, 1] {|s| if $s.0 < 20 { {value: $s.0, next: [$s.1, ($s.0 + $s.1)]} } }",
+                result: Some(Value::test_list(vec![
+                    Value::test_int(0),
+                    Value::test_int(1),
+                    Value::test_int(1),
+                    Value::test_int(2),
+                    Value::test_int(3),
+                    Value::test_int(5),
+                    Value::test_int(8),
+                    Value::test_int(13),
+                ])),
+            },
+            Example {
+                description: "Start from a `null` state, and end by leaving out `next`",
+                example: "fp unfold null {|s| if $s == null { {value: 0, next: 1} } else if $s < 2 { {value: $s, next: ($s + 1)} } else { {value: $s} } }",
+                result: Some(Value::test_list(vec![
+                    Value::test_int(0),
+                    Value::test_int(1),
+                    Value::test_int(2),
+                ])),
+            },
+        ]
+    }
+}
+
+/// Call the closure on `state`, returns the element and the next state, or `None` to stop.
+fn step(
+    plugin: &FpPlugin,
+    engine: &EngineInterface,
+    function: &FunctionValue,
+    state: Value,
+) -> Result<Option<(Value, Option<Value>)>, LabeledError> {
+    let step = function.call(plugin, engine, vec![state.clone()], Some(state))?;
+    if step.is_nothing() {
+        return Ok(None);
+    }
+
+    let span = step.span();
+    let invalid = || {
+        LabeledError::new("Invalid unfold step").with_label(
+            "expected a record with `value` and `next` fields, or `null`",
+            span,
+        )
+    };
+    let mut step = step.into_record().map_err(|_| invalid())?;
+    let value = step.remove("value").ok_or_else(invalid)?;
+    Ok(Some((value, step.remove("next"))))
+}
@@ -0,0 +1,64 @@
+use nu_plugin::SimplePluginCommand;
+use nu_protocol::{Category, Example, LabeledError, Signature, Type, Value};
+
+use crate::{
+    FpPlugin,
+    values::{ResultValue, value_to_error},
+};
+
+#[derive(Clone)]
+pub struct Unwrap;
+
+impl SimplePluginCommand for Unwrap {
+    type Plugin = FpPlugin;
+
+    fn name(&self) -> &str {
+        "fp unwrap"
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build(self.name())
+            .input_output_type(Type::Custom("result".into()), Type::Any)
+            .category(Category::Custom("functional".into()))
+    }
+
+    fn description(&self) -> &str {
+        "Get the value of an `ok` result, or raise the error of an `err` result."
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"The error keeps the message, labels and help of the original error."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["result", "error", "raise", "throw"]
+    }
+
+    fn run(
+        &self,
+        _plugin: &Self::Plugin,
+        _engine: &nu_plugin::EngineInterface,
+        call: &nu_plugin::EvaluatedCall,
+        input: &Value,
+    ) -> Result<Value, LabeledError> {
+        match ResultValue::from_value(input)? {
+            ResultValue::Ok(value) => Ok(value.clone()),
+            ResultValue::Err(error) => Err(value_to_error(error, call.head)),
+        }
+    }
+
+    fn examples(&self) -> Vec<nu_protocol::Example<'_>> {
+        vec![
+            Example {
+                description: "Get the value of `ok`",
+                example: "'1' | fp try { into int } | fp unwrap",
+                result: Some(Value::test_int(1)),
+            },
+            Example {
+                description: "Raise the error of `err`",
+                example: "'a' | fp try { into int } | fp unwrap",
+                result: None,
+            },
+        ]
+    }
+}
@@ -0,0 +1,75 @@
+use nu_plugin::SimplePluginCommand;
+use nu_protocol::{Category, Example, LabeledError, Signature, SyntaxShape, Type, Value};
+
+use crate::{FpPlugin, values::MaybeValue};
+
+use super::other_::eval_fallback;
+
+#[derive(Clone)]
+pub struct UnwrapOr;
+
+impl SimplePluginCommand for UnwrapOr {
+    type Plugin = FpPlugin;
+
+    fn name(&self) -> &str {
+        "fp unwrap-or"
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build(self.name())
+            .input_output_type(Type::Custom("maybe".into()), Type::Any)
+            .required(
+                "value",
+                SyntaxShape::Any,
+                "The value (or how to produce the value) to use when the value is absent.",
+            )
+            .category(Category::Custom("functional".into()))
+    }
+
+    fn description(&self) -> &str {
+        "Get the value of a maybe value, or a fallback if it is absent."
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"The fallback can be a direct value, or a closure producing the value, same as `fp other`."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["maybe", "option", "default", "unwrap"]
+    }
+
+    fn run(
+        &self,
+        plugin: &Self::Plugin,
+        engine: &nu_plugin::EngineInterface,
+        call: &nu_plugin::EvaluatedCall,
+        input: &Value,
+    ) -> Result<Value, LabeledError> {
+        match MaybeValue::from_value(input)? {
+            MaybeValue::Some(value) => Ok(value.clone()),
+            MaybeValue::None => {
+                eval_fallback(plugin, engine, call.req(0)?, &Value::nothing(call.head))
+            }
+        }
+    }
+
+    fn examples(&self) -> Vec<nu_protocol::Example<'_>> {
+        vec![
+            Example {
+                description: "Get a present value",
+                example: "fp some 1 | fp unwrap-or 0",
+                result: Some(Value::test_int(1)),
+            },
+            Example {
+                description: "Use 0 for an absent value",
+                example: "fp none | fp unwrap-or 0",
+                result: Some(Value::test_int(0)),
+            },
+            Example {
+                description: "Produce the fallback with closure",
+                example: "let foo = 100; fp none | fp unwrap-or {|| $foo + 2}",
+                result: Some(Value::test_int(102)),
+            },
+        ]
+    }
+}
@@ -0,0 +1,154 @@
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    Category, Example, LabeledError, PipelineData, Record, Signature, Span, Type, Value, record,
+};
+
+use crate::FpPlugin;
+
+use super::condition::elements;
+
+#[derive(Clone)]
+pub struct Unzip;
+
+impl PluginCommand for Unzip {
+    type Plugin = FpPlugin;
+
+    fn name(&self) -> &str {
+        "fp unzip"
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![
+                (
+                    Type::List(Box::new(Type::List(Box::new(Type::Any)))),
+                    Type::List(Box::new(Type::List(Box::new(Type::Any)))),
+                ),
+                (Type::table(), Type::record()),
+            ])
+            .category(Category::Filters)
+    }
+
+    fn description(&self) -> &str {
+        "Split a list of lists or records into columns."
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"For a list of lists, returns a list of lists where the n-th list contains the n-th element of each input list.
+
+For a list of records (a table), returns a record where each column is a list of the values in that column.
+
+Missing elements in shorter lists or missing columns in records are `null`."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["zip", "transpose", "columns", "split"]
+    }
+
+    fn run(
+        &self,
+        _plugin: &Self::Plugin,
+        engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let rows = elements(input).collect::<Result<Vec<_>, _>>()?;
+        engine.signals().check(&call.head)?;
+
+        let value = if !rows.is_empty() && rows.iter().all(|row| row.as_record().is_ok()) {
+            unzip_records(&rows, call.head)
+        } else {
+            unzip_lists(&rows, call.head)?
+        };
+
+        Ok(PipelineData::Value(value, None))
+    }
+
+    fn examples(&self) -> Vec<nu_protocol::Example<'_>> {
+        vec![
+            Example {
+                description: "Split a list of pairs into two lists",
+                example: "[[1, a], [2, b], [3, c]] | fp unzip",
+                result: Some(Value::test_list(vec![
+                    Value::test_list(vec![
+                        Value::test_int(1),
+                        Value::test_int(2),
+                        Value::test_int(3),
+                    ]),
+                    Value::test_list(vec![
+                        Value::test_string("a"),
+                        Value::test_string("b"),
+                        Value::test_string("c"),
+                    ]),
+                ])),
+            },
+            Example {
+                description: "Split a table into columns",
+                example: r#"[{name: "Alice", rank: 10}, {name: "Bob"}] | fp unzip"#,
+                result: Some(Value::test_record(record! {
+                    "name" => Value::test_list(vec![
+                        Value::test_string("Alice"),
+                        Value::test_string("Bob"),
+                    ]),
+                    "rank" => Value::test_list(vec![Value::test_int(10), Value::test_nothing()]),
+                })),
+            },
+        ]
+    }
+}
+
+fn unzip_lists(rows: &[Value], span: Span) -> Result<Value, LabeledError> {
+    let mut lists = Vec::with_capacity(rows.len());
+    for row in rows {
+        match row {
+            Value::List { vals, .. } => lists.push(vals.as_slice()),
+            _ => {
+                return Err(LabeledError::new("Can not unzip input").with_label(
+                    format!("expected a list, found {}", row.get_type()),
+                    row.span(),
+                ));
+            }
+        }
+    }
+
+    let width = lists.iter().map(|list| list.len()).max().unwrap_or(0);
+    let columns = (0..width)
+        .map(|n| {
+            let column = lists
+                .iter()
+                .map(|list| list.get(n).cloned().unwrap_or(Value::nothing(span)))
+                .collect();
+            Value::list(column, span)
+        })
+        .collect();
+    Ok(Value::list(columns, span))
+}
+
+fn unzip_records(rows: &[Value], span: Span) -> Value {
+    let rows = rows
+        .iter()
+        .filter_map(|row| row.as_record().ok())
+        .collect::<Vec<_>>();
+
+    // Keep columns in the order of first appearance.
+    let mut names: Vec<&String> = vec![];
+    for row in &rows {
+        for name in row.columns() {
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+    }
+
+    let columns = names
+        .into_iter()
+        .map(|name| {
+            let column = rows
+                .iter()
+                .map(|row| row.get(name).cloned().unwrap_or(Value::nothing(span)))
+                .collect();
+            (name.clone(), Value::list(column, span))
+        })
+        .collect::<Record>();
+    Value::record(columns, span)
+}
@@ -0,0 +1,150 @@
+use nu_plugin::{EngineInterface, EvaluatedCall, PluginCommand};
+use nu_protocol::{
+    Category, Example, LabeledError, ListStream, PipelineData, Signature, SyntaxShape, Type, Value,
+};
+
+use crate::{FpPlugin, values::FunctionValue};
+
+use super::condition::elements;
+
+#[derive(Clone)]
+pub struct ZipWith;
+
+impl PluginCommand for ZipWith {
+    type Plugin = FpPlugin;
+
+    fn name(&self) -> &str {
+        "fp zip-with"
+    }
+
+    fn signature(&self) -> nu_protocol::Signature {
+        Signature::build(self.name())
+            .input_output_types(vec![
+                (
+                    Type::List(Box::new(Type::Any)),
+                    Type::List(Box::new(Type::Any)),
+                ),
+                (Type::Range, Type::List(Box::new(Type::Any))),
+            ])
+            .required(
+                "closure",
+                SyntaxShape::OneOf(vec![SyntaxShape::Closure(None), SyntaxShape::Any]),
+                "The closure to combine the elements at the same position.",
+            )
+            .rest(
+                "lists",
+                SyntaxShape::OneOf(vec![
+                    SyntaxShape::List(Box::new(SyntaxShape::Any)),
+                    SyntaxShape::Range,
+                ]),
+                "Other lists to combine with the input.",
+            )
+            .switch(
+                "longest",
+                "Stop at the longest list instead of the shortest, missing elements are `null`",
+                Some('l'),
+            )
+            .category(Category::Filters)
+    }
+
+    fn description(&self) -> &str {
+        "Combine the elements at the same position in several lists with a closure."
+    }
+
+    fn extra_description(&self) -> &str {
+        r#"Call the closure with the elements at the same position in input and the other lists, e.g. `{|a, b, c| ...}` for input and two lists, and output the results.
+
+Stops when the shortest list ends, or with `--longest`, when the longest list ends with `null` for the elements in shorter lists. The input is consumed lazily as a stream."#
+    }
+
+    fn search_terms(&self) -> Vec<&str> {
+        vec!["zip", "combine", "merge", "map2", "zipWith"]
+    }
+
+    fn run(
+        &self,
+        plugin: &Self::Plugin,
+        engine: &EngineInterface,
+        call: &EvaluatedCall,
+        input: PipelineData,
+    ) -> Result<PipelineData, LabeledError> {
+        let function = FunctionValue::from_callable(call.req(0)?)?;
+        let longest = call.has_flag("longest")?;
+        let signals = engine.signals().clone();
+        let plugin = plugin.clone();
+        let engine = engine.clone();
+        let head = call.head;
+
+        let mut lists = vec![elements(input)];
+        for list in call.rest::<Value>(1)? {
+            lists.push(elements(PipelineData::Value(list, None)));
+        }
+
+        let mut done = false;
+        let stream = std::iter::from_fn(move || {
+            if done {
+                return None;
+            }
+
+            let mut args = Vec::with_capacity(lists.len());
+            let mut ended = 0;
+            for list in lists.iter_mut() {
+                match list.next() {
+                    Some(Ok(value)) => args.push(value),
+                    Some(Err(error)) => {
+                        done = true;
+                        return Some(Value::error(error.into(), head));
+                    }
+                    None => {
+                        ended += 1;
+                        args.push(Value::nothing(head));
+                    }
+                }
+            }
+            if ended == args.len() || (ended > 0 && !longest) {
+                return None;
+            }
+
+            match function.call(&plugin, &engine, args, None) {
+                Ok(value) => Some(value),
+                Err(error) => {
+                    done = true;
+                    Some(Value::error(error.into(), head))
+                }
+            }
+        });
+
+        Ok(PipelineData::ListStream(
+            ListStream::new(stream, head, signals),
+            None,
+        ))
+    }
+
+    fn examples(&self) -> Vec<nu_protocol::Example<'_>> {
+        vec![
+            Example {
+                description: "Add the elements in two lists",
+                example: "[1, 2, 3] | fp zip-with {|a, b| $a + $b} [10, 20, 30]",
+                result: Some(Value::test_list(vec![
+                    Value::test_int(11),
+                    Value::test_int(22),
+                    Value::test_int(33),
+                ])),
+            },
+            Example {
+                description: "Combine three lists into records, stop at the shortest",
+                example: "[a, b, c] | fp zip-with {|name, rank, age| {name: $name, rank: $rank, age: $age}} [1, 2] 1..",
+                result: None,
+            },
+            Example {
+                description: "Combine two lists, stop at the longest",
+                example: "[1, 2, 3] | fp zip-with --longest {|a, b| $b | default $a} [10]",
+                result: Some(Value::test_list(vec![
+                    Value::test_int(10),
+                    Value::test_int(2),
+                    Value::test_int(3),
+                ])),
+            },
+        ]
+    }
+}
@@ -0,0 +1,290 @@
+//! Plugin configuration, read from `$env.config.plugins.functional`.
+//!
+//! ```nu
+//! $env.config.plugins.functional = {
+//!     pure: {
+//!         empty: [int, float, string, bool, duration, filesize, list, record, table]
+//!     }
+//!     first_where: {
+//!         not_found: null
+//!     }
+//!     memoize: {
+//!         max_entries: null
+//!     }
+//! }
+//! ```
+
+use nu_protocol::{LabeledError, Record, Value, did_you_mean};
+
+/// Sections of `$env.config.plugins.functional`.
+const SECTIONS: [&str; 3] = [
+    PureConfig::NAME,
+    FirstWhereConfig::NAME,
+    MemoizeConfig::NAME,
+];
+
+/// A section of the plugin config, e.g. `pure` in `$env.config.plugins.functional.pure`.
+///
+/// Commands read and validate only the section they use, so an invalid section does not break
+/// commands not using it.
+pub trait Section: Default {
+    /// Key of the section in `$env.config.plugins.functional`.
+    const NAME: &'static str;
+
+    /// Set a key of the section, unknown keys and invalid values are errors.
+    fn set(&mut self, key: &str, value: &Value) -> Result<(), LabeledError>;
+
+    /// Parse the section from the value of `$env.config.plugins.functional`.
+    ///
+    /// Missing keys use the default value. Unknown section names are errors too, so a misspelled
+    /// section is not silently ignored.
+    fn from_config(config: Option<&Value>) -> Result<Self, LabeledError> {
+        let mut section = Self::default();
+        let Some(config) = config.filter(|config| !config.is_nothing()) else {
+            return Ok(section);
+        };
+        let config = as_record(config, "functional")?;
+        if let Some((name, value)) = config
+            .iter()
+            .find(|(name, _)| !SECTIONS.contains(&name.as_str()))
+        {
+            return Err(unknown_key("functional", name, value, &SECTIONS));
+        }
+        let Some(value) = config.get(Self::NAME) else {
+            return Ok(section);
+        };
+        for (key, value) in as_record(value, Self::NAME)? {
+            section.set(key, value)?;
+        }
+        Ok(section)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct PureConfig {
+    /// Types of which the default value is treated as empty.
+    pub empty: Vec<EmptyType>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct FirstWhereConfig {
+    /// What to do when no element meets the condition, if no flag is given.
+    pub not_found: NotFound,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct MemoizeConfig {
+    /// Maximum number of cached results of each memoized function, the oldest results are
+    /// dropped when reached.
+    ///
+    /// `None` for no limit.
+    pub max_entries: Option<usize>,
+}
+
+/// Types `fp pure` can treat the default value of as empty.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EmptyType {
+    Int,
+    Float,
+    String,
+    Bool,
+    Duration,
+    Filesize,
+    List,
+    Record,
+    Table,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum NotFound {
+    #[default]
+    Null,
+    Error,
+}
+
+impl Default for PureConfig {
+    fn default() -> Self {
+        Self {
+            empty: EmptyType::ALL.iter().map(|(_, ty)| *ty).collect(),
+        }
+    }
+}
+
+impl EmptyType {
+    const ALL: [(&str, EmptyType); 9] = [
+        ("int", EmptyType::Int),
+        ("float", EmptyType::Float),
+        ("string", EmptyType::String),
+        ("bool", EmptyType::Bool),
+        ("duration", EmptyType::Duration),
+        ("filesize", EmptyType::Filesize),
+        ("list", EmptyType::List),
+        ("record", EmptyType::Record),
+        ("table", EmptyType::Table),
+    ];
+}
+
+impl Section for PureConfig {
+    const NAME: &'static str = "pure";
+
+    fn set(&mut self, key: &str, value: &Value) -> Result<(), LabeledError> {
+        match key {
+            "empty" => self.empty = parse_empty(value)?,
+            _ => return Err(unknown_key(Self::NAME, key, value, &["empty"])),
+        }
+        Ok(())
+    }
+}
+
+impl Section for FirstWhereConfig {
+    const NAME: &'static str = "first_where";
+
+    fn set(&mut self, key: &str, value: &Value) -> Result<(), LabeledError> {
+        match key {
+            "not_found" => self.not_found = parse_not_found(value)?,
+            _ => return Err(unknown_key(Self::NAME, key, value, &["not_found"])),
+        }
+        Ok(())
+    }
+}
+
+impl Section for MemoizeConfig {
+    const NAME: &'static str = "memoize";
+
+    fn set(&mut self, key: &str, value: &Value) -> Result<(), LabeledError> {
+        match key {
+            "max_entries" => self.max_entries = parse_max_entries(value)?,
+            _ => return Err(unknown_key(Self::NAME, key, value, &["max_entries"])),
+        }
+        Ok(())
+    }
+}
+
+fn as_record<'a>(value: &'a Value, name: &str) -> Result<&'a Record, LabeledError> {
+    value
+        .as_record()
+        .map_err(|_| invalid_value(name, "expected a record", value))
+}
+
+fn parse_empty(value: &Value) -> Result<Vec<EmptyType>, LabeledError> {
+    let supported = || {
+        let names = EmptyType::ALL.map(|(name, _)| name).join(", ");
+        format!("supported types are: {names}")
+    };
+    let Ok(values) = value.as_list() else {
+        return Err(
+            invalid_value("pure.empty", "expected a list of type names", value)
+                .with_help(supported()),
+        );
+    };
+
+    values
+        .iter()
+        .map(|value| {
+            let name = value.as_str().map_err(|_| {
+                invalid_value("pure.empty", "expected a type name", value).with_help(supported())
+            })?;
+            EmptyType::ALL
+                .iter()
+                .find(|(ty_name, _)| *ty_name == name)
+                .map(|(_, ty)| *ty)
+                .ok_or_else(|| {
+                    invalid_value("pure.empty", format!("unsupported type `{name}`"), value)
+                        .with_help(supported())
+                })
+        })
+        .collect()
+}
+
+fn parse_not_found(value: &Value) -> Result<NotFound, LabeledError> {
+    match value {
+        Value::Nothing { .. } => Ok(NotFound::Null),
+        Value::String { val, .. } if val == "null" => Ok(NotFound::Null),
+        Value::String { val, .. } if val == "error" => Ok(NotFound::Error),
+        _ => Err(invalid_value(
+            "first_where.not_found",
+            "expected `null` or \"error\"",
+            value,
+        )),
+    }
+}
+
+fn parse_max_entries(value: &Value) -> Result<Option<usize>, LabeledError> {
+    match value {
+        Value::Nothing { .. } => Ok(None),
+        Value::Int { val, .. } if *val >= 0 => Ok(Some(*val as usize)),
+        _ => Err(invalid_value(
+            "memoize.max_entries",
+            "expected a non-negative int or `null`",
+            value,
+        )),
+    }
+}
+
+fn invalid_value(name: &str, expected: impl Into<String>, value: &Value) -> LabeledError {
+    LabeledError::new(format!("Invalid plugin config `{name}`"))
+        .with_label(expected, value.span())
+        .with_help("check `$env.config.plugins.functional`")
+}
+
+fn unknown_key(parent: &str, key: &str, value: &Value, keys: &[&str]) -> LabeledError {
+    let help = match did_you_mean(keys, key) {
+        Some(suggestion) => format!("did you mean `{suggestion}`?"),
+        None => format!("supported keys are: {}", keys.join(", ")),
+    };
+    LabeledError::new(format!("Invalid plugin config `{parent}`"))
+        .with_label(format!("unknown key `{key}`"), value.span())
+        .with_help(help)
+}
+
+#[cfg(test)]
+mod tests {
+    use nu_protocol::{Value, record};
+
+    use super::*;
+
+    #[test]
+    fn missing_config_uses_defaults() {
+        let config = MemoizeConfig::from_config(None).unwrap();
+        assert_eq!(config.max_entries, None);
+
+        let config = Value::test_record(record! {});
+        let config = FirstWhereConfig::from_config(Some(&config)).unwrap();
+        assert_eq!(config.not_found, NotFound::Null);
+    }
+
+    #[test]
+    fn reads_section() {
+        let config = Value::test_record(record! {
+            "memoize" => Value::test_record(record! { "max_entries" => Value::test_int(3) }),
+        });
+        let config = MemoizeConfig::from_config(Some(&config)).unwrap();
+        assert_eq!(config.max_entries, Some(3));
+    }
+
+    #[test]
+    fn other_sections_are_not_validated() {
+        let config = Value::test_record(record! {
+            "memoize" => Value::test_record(record! { "max_entries" => Value::test_int(-1) }),
+        });
+        assert!(FirstWhereConfig::from_config(Some(&config)).is_ok());
+        assert!(MemoizeConfig::from_config(Some(&config)).is_err());
+    }
+
+    #[test]
+    fn unknown_sections_are_errors() {
+        let config = Value::test_record(record! {
+            "first-where" => Value::test_record(record! { "not_found" => Value::test_string("error") }),
+        });
+        let error = PureConfig::from_config(Some(&config)).unwrap_err();
+        assert_eq!(error.labels[0].text, "unknown key `first-where`");
+        assert_eq!(error.help.as_deref(), Some("did you mean `first_where`?"));
+
+        let config = Value::test_record(record! { "foo" => Value::test_nothing() });
+        let error = PureConfig::from_config(Some(&config)).unwrap_err();
+        assert_eq!(
+            error.help.as_deref(),
+            Some("supported keys are: pure, first_where, memoize")
+        );
+    }
+}
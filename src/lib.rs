@@ -1,10 +1,41 @@
-use nu_plugin::Plugin;
+use std::sync::Arc;
 
-use crate::commands::{FirstWhere, Is, Main, Other, Pure, Then};
+use nu_plugin::{EngineInterface, Plugin};
+use nu_protocol::LabeledError;
+
+use crate::commands::{
+    AndThen, AssertType, Bind, Break, Call, ChunkBy, Coalesce, Compose, DropWhile, FindIndex,
+    FindLastIndex, FirstWhere, Fold, Is, IsOk, Iterate, LastWhere, Main, Map, MapErr, MapOk,
+    MemoClear, MemoStats, Memoize, None_, Other, Partial, Partition, Pipe, Pure, Scan, Some_,
+    SpanCmd, TakeWhile, Then, ToNullable, Try, Unfold, Unwrap, UnwrapOr, Unzip, ZipWith,
+};
+use crate::config::Section;
+use crate::memo::MemoCache;
 
 mod commands;
+mod config;
+mod memo;
+mod type_pattern;
+mod values;
+
+#[derive(Clone, Default)]
+pub struct FpPlugin {
+    memo: Arc<MemoCache>,
+}
 
-pub struct FpPlugin;
+impl FpPlugin {
+    /// Cache of memoized function results.
+    pub(crate) fn memo(&self) -> &MemoCache {
+        &self.memo
+    }
+
+    /// Read a section of the plugin configuration from `$env.config.plugins.functional`.
+    ///
+    /// Commands should read the config once per run, each read is a call to the engine.
+    pub(crate) fn config<T: Section>(&self, engine: &EngineInterface) -> Result<T, LabeledError> {
+        T::from_config(engine.get_plugin_config()?.as_ref())
+    }
+}
 
 impl Plugin for FpPlugin {
     fn version(&self) -> String {
@@ -13,12 +44,48 @@ impl Plugin for FpPlugin {
 
     fn commands(&self) -> Vec<Box<dyn nu_plugin::PluginCommand<Plugin = Self>>> {
         vec![
+            Box::new(AndThen),
+            Box::new(AssertType),
+            Box::new(Bind),
+            Box::new(Break),
+            Box::new(Call),
+            Box::new(ChunkBy),
+            Box::new(Coalesce),
+            Box::new(Compose),
+            Box::new(DropWhile),
+            Box::new(FindIndex),
+            Box::new(FindLastIndex),
             Box::new(FirstWhere),
+            Box::new(Fold),
             Box::new(Is),
+            Box::new(IsOk),
+            Box::new(Iterate),
+            Box::new(LastWhere),
             Box::new(Main),
+            Box::new(Map),
+            Box::new(MapErr),
+            Box::new(MapOk),
+            Box::new(MemoClear),
+            Box::new(MemoStats),
+            Box::new(Memoize),
+            Box::new(None_),
             Box::new(Other),
+            Box::new(Partial),
+            Box::new(Partition),
+            Box::new(Pipe),
             Box::new(Pure),
+            Box::new(Scan),
+            Box::new(Some_),
+            Box::new(SpanCmd),
+            Box::new(TakeWhile),
             Box::new(Then),
+            Box::new(ToNullable),
+            Box::new(Try),
+            Box::new(Unfold),
+            Box::new(Unwrap),
+            Box::new(UnwrapOr),
+            Box::new(Unzip),
+            Box::new(ZipWith),
         ]
     }
 }
@@ -0,0 +1,399 @@
+//! Cache of memoized function results, kept in the plugin state for the plugin session.
+
+use std::{
+    collections::{HashMap, VecDeque},
+    hash::{DefaultHasher, Hash, Hasher},
+    sync::Mutex,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use nu_protocol::{Span, Value};
+
+#[derive(Debug, Default)]
+pub struct MemoCache {
+    inner: Mutex<Inner>,
+}
+
+#[derive(Debug, Default)]
+struct Inner {
+    /// Cached results by memoized function id and hash of call arguments.
+    ///
+    /// Arguments with the same hash are compared with [`value_eq`] to handle hash collisions.
+    entries: HashMap<(u64, u64), Vec<Entry>>,
+    /// Argument hashes of cached results by function id, from the oldest to the newest, to drop
+    /// the oldest results of a function first.
+    order: HashMap<u64, VecDeque<u64>>,
+    len: usize,
+    hits: u64,
+    misses: u64,
+    next_id: u64,
+}
+
+#[derive(Debug)]
+struct Entry {
+    args: Vec<Value>,
+    result: Value,
+}
+
+/// Statistics of the cache.
+#[derive(Clone, Copy, Debug)]
+pub struct MemoStats {
+    pub functions: usize,
+    pub entries: usize,
+    pub hits: u64,
+    pub misses: u64,
+}
+
+impl MemoCache {
+    /// Generate an id for a new memoized function.
+    ///
+    /// Memoized functions may outlive the plugin process, so the id is salted with the current
+    /// time to avoid sharing cached results with a function memoized by a previous process.
+    pub fn new_id(&self) -> u64 {
+        let mut inner = self.inner.lock().unwrap();
+        inner.next_id += 1;
+        let mut hasher = DefaultHasher::new();
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_nanos()
+            .hash(&mut hasher);
+        inner.next_id.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Get the cached result of calling function `id` with `args`.
+    pub fn get(&self, id: u64, args: &[Value]) -> Option<Value> {
+        let mut inner = self.inner.lock().unwrap();
+        let result = inner
+            .entries
+            .get(&(id, hash_args(args)))
+            .and_then(|entries| entries.iter().find(|entry| args_eq(&entry.args, args)))
+            .map(|entry| entry.result.clone());
+        if result.is_some() {
+            inner.hits += 1;
+        } else {
+            inner.misses += 1;
+        }
+        result
+    }
+
+    /// Cache the result of calling function `id` with `args`.
+    ///
+    /// If function `id` has `max_entries` cached results already, its oldest ones are dropped,
+    /// cached results of other functions are kept.
+    pub fn insert(&self, id: u64, args: Vec<Value>, result: Value, max_entries: Option<usize>) {
+        if max_entries == Some(0) {
+            return;
+        }
+        let mut inner = self.inner.lock().unwrap();
+        let Inner {
+            entries,
+            order,
+            len,
+            ..
+        } = &mut *inner;
+        let order = order.entry(id).or_default();
+        while max_entries.is_some_and(|max| order.len() >= max) {
+            let Some(hash) = order.pop_front() else {
+                break;
+            };
+            if let Some(oldest) = entries.get_mut(&(id, hash)) {
+                oldest.remove(0);
+                if oldest.is_empty() {
+                    entries.remove(&(id, hash));
+                }
+                *len -= 1;
+            }
+        }
+
+        let hash = hash_args(&args);
+        entries
+            .entry((id, hash))
+            .or_default()
+            .push(Entry { args, result });
+        order.push_back(hash);
+        *len += 1;
+    }
+
+    /// Remove cached results of function `id`, or of all functions if `id` is `None`.
+    ///
+    /// Returns the number of removed results.
+    pub fn clear(&self, id: Option<u64>) -> usize {
+        let mut inner = self.inner.lock().unwrap();
+        let before = inner.len;
+        match id {
+            Some(id) => {
+                inner.entries.retain(|(entry_id, _), _| *entry_id != id);
+                inner.order.remove(&id);
+            }
+            None => {
+                inner.entries.clear();
+                inner.order.clear();
+                inner.hits = 0;
+                inner.misses = 0;
+            }
+        }
+        inner.len = inner.entries.values().map(Vec::len).sum();
+        before - inner.len
+    }
+
+    pub fn stats(&self) -> MemoStats {
+        let inner = self.inner.lock().unwrap();
+        let mut functions = inner.entries.keys().map(|(id, _)| *id).collect::<Vec<_>>();
+        functions.sort_unstable();
+        functions.dedup();
+        MemoStats {
+            functions: functions.len(),
+            entries: inner.len,
+            hits: inner.hits,
+            misses: inner.misses,
+        }
+    }
+}
+
+fn hash_args(args: &[Value]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    args.len().hash(&mut hasher);
+    for arg in args {
+        hash_value(arg, &mut hasher);
+    }
+    hasher.finish()
+}
+
+fn args_eq(a: &[Value], b: &[Value]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).all(|(a, b)| value_eq(a, b))
+}
+
+/// Compare the content of values consistently with [`hash_value`], spans are ignored.
+///
+/// Unlike `==` on values, floats are compared by bits so `NaN` equals itself, and custom values
+/// are compared by type name and base value.
+fn value_eq(a: &Value, b: &Value) -> bool {
+    match (a, b) {
+        (Value::Float { val: a, .. }, Value::Float { val: b, .. }) => a.to_bits() == b.to_bits(),
+        (Value::Record { val: a, .. }, Value::Record { val: b, .. }) => {
+            a.len() == b.len()
+                && a.iter()
+                    .zip(b.iter())
+                    .all(|((a_name, a), (b_name, b))| a_name == b_name && value_eq(a, b))
+        }
+        (Value::List { vals: a, .. }, Value::List { vals: b, .. }) => args_eq(a, b),
+        (Value::Closure { val: a, .. }, Value::Closure { val: b, .. }) => {
+            a.block_id == b.block_id
+                && a.captures.len() == b.captures.len()
+                && a.captures
+                    .iter()
+                    .zip(&b.captures)
+                    .all(|((a_id, a), (b_id, b))| a_id == b_id && value_eq(a, b))
+        }
+        (Value::Error { error: a, .. }, Value::Error { error: b, .. }) => {
+            a.to_string() == b.to_string()
+        }
+        (Value::Custom { val: a, .. }, Value::Custom { val: b, .. }) => {
+            a.type_name() == b.type_name()
+                && match (
+                    a.to_base_value(Span::unknown()),
+                    b.to_base_value(Span::unknown()),
+                ) {
+                    (Ok(a), Ok(b)) => value_eq(&a, &b),
+                    _ => false,
+                }
+        }
+        (
+            Value::Float { .. }
+            | Value::Record { .. }
+            | Value::List { .. }
+            | Value::Closure { .. }
+            | Value::Error { .. }
+            | Value::Custom { .. },
+            _,
+        ) => false,
+        _ => std::mem::discriminant(a) == std::mem::discriminant(b) && a == b,
+    }
+}
+
+/// Hash the content of a value, spans are ignored.
+fn hash_value(value: &Value, state: &mut impl Hasher) {
+    std::mem::discriminant(value).hash(state);
+    match value {
+        Value::Bool { val, .. } => val.hash(state),
+        Value::Int { val, .. } => val.hash(state),
+        Value::Float { val, .. } => val.to_bits().hash(state),
+        Value::String { val, .. } => val.hash(state),
+        Value::Glob { val, no_expand, .. } => {
+            val.hash(state);
+            no_expand.hash(state);
+        }
+        Value::Filesize { val, .. } => val.hash(state),
+        Value::Duration { val, .. } => val.hash(state),
+        Value::Date { val, .. } => val.hash(state),
+        Value::Range { val, .. } => format!("{val:?}").hash(state),
+        Value::Record { val, .. } => {
+            val.len().hash(state);
+            for (name, value) in val.iter() {
+                name.hash(state);
+                hash_value(value, state);
+            }
+        }
+        Value::List { vals, .. } => {
+            vals.len().hash(state);
+            for value in vals {
+                hash_value(value, state);
+            }
+        }
+        Value::Closure { val, .. } => {
+            val.block_id.get().hash(state);
+            for (var_id, value) in &val.captures {
+                var_id.get().hash(state);
+                hash_value(value, state);
+            }
+        }
+        Value::Error { error, .. } => error.to_string().hash(state),
+        Value::Binary { val, .. } => val.hash(state),
+        Value::CellPath { val, .. } => val.to_string().hash(state),
+        Value::Custom { val, .. } => {
+            val.type_name().hash(state);
+            if let Ok(base) = val.to_base_value(value.span()) {
+                hash_value(&base, state);
+            }
+        }
+        Value::Nothing { .. } => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nu_protocol::{Value, record};
+
+    use super::*;
+    use crate::values::MaybeValue;
+
+    fn int(val: i64) -> Value {
+        Value::test_int(val)
+    }
+
+    #[test]
+    fn hit_and_miss() {
+        let cache = MemoCache::default();
+        let id = cache.new_id();
+
+        assert_eq!(cache.get(id, &[int(1)]), None);
+        cache.insert(id, vec![int(1)], int(2), None);
+        assert_eq!(cache.get(id, &[int(1)]), Some(int(2)));
+        assert_eq!(cache.get(id, &[int(2)]), None);
+        assert_eq!(cache.get(id, &[int(1), int(1)]), None);
+        assert_eq!(cache.get(cache.new_id(), &[int(1)]), None);
+
+        let stats = cache.stats();
+        assert_eq!((stats.functions, stats.entries), (1, 1));
+        assert_eq!((stats.hits, stats.misses), (1, 4));
+    }
+
+    #[test]
+    fn spans_are_ignored() {
+        let cache = MemoCache::default();
+        let id = cache.new_id();
+
+        cache.insert(id, vec![Value::int(1, Span::new(0, 1))], int(2), None);
+        assert_eq!(
+            cache.get(id, &[Value::int(1, Span::new(5, 6))]),
+            Some(int(2))
+        );
+    }
+
+    #[test]
+    fn values_not_equal_to_themselves_hit() {
+        let cache = MemoCache::default();
+        let id = cache.new_id();
+        let args = vec![
+            Value::test_float(f64::NAN),
+            MaybeValue::Some(int(1)).into_value(Span::test_data()),
+            Value::test_record(record! { "a" => Value::test_float(f64::NAN) }),
+        ];
+
+        cache.insert(id, args.clone(), int(2), None);
+        assert_eq!(cache.get(id, &args), Some(int(2)));
+        assert_eq!(cache.stats().entries, 1);
+
+        let other = vec![
+            Value::test_float(f64::NAN),
+            MaybeValue::Some(int(2)).into_value(Span::test_data()),
+            Value::test_record(record! { "a" => Value::test_float(f64::NAN) }),
+        ];
+        assert_eq!(cache.get(id, &other), None);
+    }
+
+    #[test]
+    fn int_and_float_are_different_keys() {
+        let cache = MemoCache::default();
+        let id = cache.new_id();
+
+        cache.insert(id, vec![int(1)], int(2), None);
+        assert_eq!(cache.get(id, &[Value::test_float(1.0)]), None);
+    }
+
+    #[test]
+    fn clear() {
+        let cache = MemoCache::default();
+        let (a, b) = (cache.new_id(), cache.new_id());
+        cache.insert(a, vec![int(1)], int(1), None);
+        cache.insert(a, vec![int(2)], int(2), None);
+        cache.insert(b, vec![int(1)], int(1), None);
+        cache.get(a, &[int(1)]);
+
+        assert_eq!(cache.clear(Some(a)), 2);
+        assert_eq!(cache.get(a, &[int(1)]), None);
+        assert_eq!(cache.get(b, &[int(1)]), Some(int(1)));
+        let stats = cache.stats();
+        assert_eq!((stats.functions, stats.entries), (1, 1));
+
+        assert_eq!(cache.clear(None), 1);
+        let stats = cache.stats();
+        assert_eq!((stats.entries, stats.hits, stats.misses), (0, 0, 0));
+    }
+
+    #[test]
+    fn eviction_drops_oldest() {
+        let cache = MemoCache::default();
+        let id = cache.new_id();
+        for i in 0..5 {
+            cache.insert(id, vec![int(i)], int(i), Some(3));
+        }
+
+        assert_eq!(cache.stats().entries, 3);
+        assert_eq!(cache.get(id, &[int(0)]), None);
+        assert_eq!(cache.get(id, &[int(1)]), None);
+        assert_eq!(cache.get(id, &[int(2)]), Some(int(2)));
+        assert_eq!(cache.get(id, &[int(4)]), Some(int(4)));
+    }
+
+    #[test]
+    fn eviction_is_per_function() {
+        let cache = MemoCache::default();
+        let (limited, unlimited) = (cache.new_id(), cache.new_id());
+        for i in 0..3 {
+            cache.insert(unlimited, vec![int(i)], int(i), None);
+        }
+        for i in 0..3 {
+            cache.insert(limited, vec![int(i)], int(i), Some(1));
+        }
+
+        assert_eq!(cache.stats().entries, 4);
+        assert_eq!(cache.get(limited, &[int(1)]), None);
+        assert_eq!(cache.get(limited, &[int(2)]), Some(int(2)));
+        for i in 0..3 {
+            assert_eq!(cache.get(unlimited, &[int(i)]), Some(int(i)));
+        }
+    }
+
+    #[test]
+    fn zero_max_entries_caches_nothing() {
+        let cache = MemoCache::default();
+        let id = cache.new_id();
+        cache.insert(id, vec![int(1)], int(1), Some(0));
+
+        assert_eq!(cache.stats().entries, 0);
+        assert_eq!(cache.get(id, &[int(1)]), None);
+    }
+}
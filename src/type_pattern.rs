@@ -0,0 +1,800 @@
+//! Type patterns used by `fp is`.
+//!
+//! A type pattern is written in nushell type syntax, e.g. `int`, `list<string>` or
+//! `record<name: string, rank>`, and is checked against a value structurally rather than by
+//! comparing type names.
+
+use std::fmt;
+
+use nu_protocol::{
+    LabeledError, Span, Spanned, Value, ast::PathMember, casing::Casing, did_you_mean,
+};
+
+/// A parsed type pattern.
+///
+/// Patterns can be combined with `|` (union) and `!` (negation), `!` binds tighter than `|`
+/// and parentheses can be used for grouping, e.g. `!(int | float)`.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TypePattern {
+    Any,
+    Binary,
+
+    /// Blocks are never runtime values, so this matches nothing.
+    Block,
+    Bool,
+    CellPath,
+    Closure,
+    Date,
+    Duration,
+    Error,
+    Filesize,
+    Float,
+    Glob,
+    Int,
+    Nothing,
+
+    /// Supertype of int and float.
+    Number,
+    Range,
+    String,
+    List(Box<TypePattern>),
+    Record(Vec<FieldPattern>),
+    Table(Vec<FieldPattern>),
+
+    /// Union of patterns, written as `int | float` or `oneof<int, float>`.
+    OneOf(Vec<TypePattern>),
+
+    /// Negation of a pattern, written as `!nothing`.
+    Not(Box<TypePattern>),
+
+    /// Plugin custom values by their type name, written as `custom<function>`.
+    Custom(String),
+}
+
+/// A field in `record<...>` or a column in `table<...>`.
+///
+/// A field without type (`record<name>`) only requires the field to exist.
+#[derive(Clone, Debug, PartialEq)]
+pub struct FieldPattern {
+    pub name: String,
+    pub ty: Option<TypePattern>,
+}
+
+/// A place in the value where the pattern does not match.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Mismatch {
+    /// Path from the checked value to the mismatched value.
+    pub path: Vec<PathMember>,
+
+    /// The expected pattern.
+    pub expected: String,
+
+    /// Type of the actual value, or `missing` if a field does not exist.
+    pub actual: String,
+
+    /// Span of the mismatched value, or of the record if a field does not exist.
+    pub span: Span,
+}
+
+/// Error occurred when parsing a type pattern.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParseError {
+    pub msg: String,
+
+    /// Byte offset in the pattern text where the error occurred.
+    pub offset: usize,
+
+    /// Length in bytes of the text causing the error, `0` if it is a single position.
+    pub len: usize,
+
+    pub help: Option<String>,
+}
+
+impl TypePattern {
+    /// Parse a type pattern.
+    ///
+    /// Whitespaces between tokens are ignored, so `record<name:string,rank:int>` and
+    /// `record<name: string, rank: int>` are the same pattern.
+    pub fn parse(text: &str) -> Result<Self, ParseError> {
+        let mut parser = Parser::new(text);
+        let pattern = parser.parse_pattern()?;
+        parser.skip_whitespace();
+        match parser.peek() {
+            None => Ok(pattern),
+            Some(c) => Err(parser.error(format!("unexpected character '{c}'"))),
+        }
+    }
+
+    /// Parse a type pattern from a command argument, reporting errors on the argument's span.
+    pub fn parse_spanned(text: &Spanned<String>) -> Result<Self, LabeledError> {
+        Self::parse(&text.item).map_err(|e| {
+            // Point at the error inside the argument if the span covers the (quoted) text exactly.
+            let quote = match text.span.len().checked_sub(text.item.len()) {
+                Some(0) => Some(0),
+                Some(2) => Some(1),
+                _ => None,
+            };
+            let error = match quote {
+                Some(quote) => {
+                    let start = text.span.start + quote + e.offset;
+                    LabeledError::new("Invalid type pattern")
+                        .with_label(e.msg, Span::new(start, start + e.len.max(1)))
+                }
+                None => LabeledError::new("Invalid type pattern")
+                    .with_label(format!("{} (at position {})", e.msg, e.offset), text.span),
+            };
+            match e.help {
+                Some(help) => error.with_help(help),
+                None => error,
+            }
+        })
+    }
+
+    /// Check if `value` matches the pattern.
+    ///
+    /// Records are structurally typed: a record matches if it has all the fields in pattern,
+    /// in any order, extra fields are allowed. A table is a list where every element is a
+    /// record matching the columns.
+    pub fn matches(&self, value: &Value) -> bool {
+        match (self, value) {
+            (TypePattern::Any, _) => true,
+            (TypePattern::Binary, Value::Binary { .. })
+            | (TypePattern::Bool, Value::Bool { .. })
+            | (TypePattern::CellPath, Value::CellPath { .. })
+            | (TypePattern::Closure, Value::Closure { .. })
+            | (TypePattern::Date, Value::Date { .. })
+            | (TypePattern::Duration, Value::Duration { .. })
+            | (TypePattern::Error, Value::Error { .. })
+            | (TypePattern::Filesize, Value::Filesize { .. })
+            | (TypePattern::Float, Value::Float { .. })
+            | (TypePattern::Glob, Value::Glob { .. })
+            | (TypePattern::Int, Value::Int { .. })
+            | (TypePattern::Nothing, Value::Nothing { .. })
+            | (TypePattern::Number, Value::Int { .. } | Value::Float { .. })
+            | (TypePattern::Range, Value::Range { .. })
+            | (TypePattern::String, Value::String { .. }) => true,
+            (TypePattern::List(inner), Value::List { vals, .. }) => {
+                vals.iter().all(|v| inner.matches(v))
+            }
+            (TypePattern::Record(fields), Value::Record { val, .. }) => fields_match(fields, val),
+            (TypePattern::Table(columns), Value::List { vals, .. }) => vals.iter().all(|v| {
+                v.as_record()
+                    .is_ok_and(|record| fields_match(columns, record))
+            }),
+            (TypePattern::OneOf(patterns), _) => patterns.iter().any(|p| p.matches(value)),
+            (TypePattern::Not(pattern), _) => !pattern.matches(value),
+            (TypePattern::Custom(name), Value::Custom { val, .. }) => val.type_name() == *name,
+            _ => false,
+        }
+    }
+
+    /// Find the places in `value` not matching the pattern.
+    ///
+    /// Only the first mismatch is returned unless `all` is true. Mismatches are reported on the
+    /// deepest list element or record field possible, unions and negations are reported as a
+    /// whole.
+    pub fn mismatches(&self, value: &Value, all: bool) -> Vec<Mismatch> {
+        let mut mismatches = vec![];
+        self.collect_mismatches(value, &mut vec![], &mut mismatches, all);
+        mismatches
+    }
+
+    fn collect_mismatches(
+        &self,
+        value: &Value,
+        path: &mut Vec<PathMember>,
+        mismatches: &mut Vec<Mismatch>,
+        all: bool,
+    ) {
+        match (self, value) {
+            (TypePattern::List(inner), Value::List { vals, .. }) => {
+                for (index, v) in vals.iter().enumerate() {
+                    if !all && !mismatches.is_empty() {
+                        return;
+                    }
+                    path.push(PathMember::int(index, false, v.span()));
+                    inner.collect_mismatches(v, path, mismatches, all);
+                    path.pop();
+                }
+            }
+            (TypePattern::Record(fields), Value::Record { .. }) => {
+                collect_field_mismatches(fields, value, path, mismatches, all);
+            }
+            (TypePattern::Table(columns), Value::List { vals, .. }) => {
+                for (index, v) in vals.iter().enumerate() {
+                    if !all && !mismatches.is_empty() {
+                        return;
+                    }
+                    path.push(PathMember::int(index, false, v.span()));
+                    if v.as_record().is_ok() {
+                        collect_field_mismatches(columns, v, path, mismatches, all);
+                    } else {
+                        mismatches.push(Mismatch {
+                            path: path.clone(),
+                            expected: TypePattern::Record(columns.clone()).to_string(),
+                            actual: v.get_type().to_string(),
+                            span: v.span(),
+                        });
+                    }
+                    path.pop();
+                }
+            }
+            _ => {
+                if !self.matches(value) {
+                    mismatches.push(Mismatch {
+                        path: path.clone(),
+                        expected: self.to_string(),
+                        actual: value.get_type().to_string(),
+                        span: value.span(),
+                    });
+                }
+            }
+        }
+    }
+}
+
+fn collect_field_mismatches(
+    fields: &[FieldPattern],
+    value: &Value,
+    path: &mut Vec<PathMember>,
+    mismatches: &mut Vec<Mismatch>,
+    all: bool,
+) {
+    let Ok(record) = value.as_record() else {
+        return;
+    };
+    for field in fields {
+        if !all && !mismatches.is_empty() {
+            return;
+        }
+        path.push(PathMember::string(
+            field.name.clone(),
+            false,
+            Casing::Sensitive,
+            value.span(),
+        ));
+        match (record.get(&field.name), &field.ty) {
+            (Some(v), Some(ty)) => ty.collect_mismatches(v, path, mismatches, all),
+            (Some(_), None) => {}
+            (None, ty) => mismatches.push(Mismatch {
+                path: path.clone(),
+                expected: ty.as_ref().unwrap_or(&TypePattern::Any).to_string(),
+                actual: "missing".into(),
+                span: value.span(),
+            }),
+        }
+        path.pop();
+    }
+}
+
+impl fmt::Display for TypePattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TypePattern::Any => write!(f, "any"),
+            TypePattern::Binary => write!(f, "binary"),
+            TypePattern::Block => write!(f, "block"),
+            TypePattern::Bool => write!(f, "bool"),
+            TypePattern::CellPath => write!(f, "cell-path"),
+            TypePattern::Closure => write!(f, "closure"),
+            TypePattern::Date => write!(f, "datetime"),
+            TypePattern::Duration => write!(f, "duration"),
+            TypePattern::Error => write!(f, "error"),
+            TypePattern::Filesize => write!(f, "filesize"),
+            TypePattern::Float => write!(f, "float"),
+            TypePattern::Glob => write!(f, "glob"),
+            TypePattern::Int => write!(f, "int"),
+            TypePattern::Nothing => write!(f, "nothing"),
+            TypePattern::Number => write!(f, "number"),
+            TypePattern::Range => write!(f, "range"),
+            TypePattern::String => write!(f, "string"),
+            TypePattern::List(inner) => write!(f, "list<{inner}>"),
+            TypePattern::Record(fields) => write_fields(f, "record", fields),
+            TypePattern::Table(columns) => write_fields(f, "table", columns),
+            TypePattern::OneOf(patterns) => {
+                let patterns = patterns.iter().map(|p| p.to_string()).collect::<Vec<_>>();
+                write!(f, "{}", patterns.join(" | "))
+            }
+            TypePattern::Not(pattern) => match pattern.as_ref() {
+                TypePattern::OneOf(_) => write!(f, "!({pattern})"),
+                _ => write!(f, "!{pattern}"),
+            },
+            TypePattern::Custom(name) => write!(f, "custom<{name}>"),
+        }
+    }
+}
+
+fn write_fields(f: &mut fmt::Formatter<'_>, name: &str, fields: &[FieldPattern]) -> fmt::Result {
+    write!(f, "{name}")?;
+    if fields.is_empty() {
+        return Ok(());
+    }
+    let fields = fields
+        .iter()
+        .map(|field| {
+            let name = if !field.name.is_empty()
+                && field
+                    .name
+                    .chars()
+                    .all(|c| c.is_alphanumeric() || c == '_' || c == '-')
+            {
+                field.name.clone()
+            } else {
+                format!("\"{}\"", field.name)
+            };
+            match &field.ty {
+                Some(ty) => format!("{name}: {ty}"),
+                None => name,
+            }
+        })
+        .collect::<Vec<_>>();
+    write!(f, "<{}>", fields.join(", "))
+}
+
+fn fields_match(fields: &[FieldPattern], record: &nu_protocol::Record) -> bool {
+    fields.iter().all(|field| match record.get(&field.name) {
+        Some(v) => field.ty.as_ref().is_none_or(|ty| ty.matches(v)),
+        None => false,
+    })
+}
+
+/// Type names accepted by the parser.
+const TYPE_NAMES: [&str; 23] = [
+    "any",
+    "binary",
+    "block",
+    "bool",
+    "cell-path",
+    "closure",
+    "custom",
+    "date",
+    "datetime",
+    "duration",
+    "error",
+    "filesize",
+    "float",
+    "glob",
+    "int",
+    "list",
+    "nothing",
+    "number",
+    "oneof",
+    "range",
+    "record",
+    "string",
+    "table",
+];
+
+struct Parser<'a> {
+    text: &'a str,
+    offset: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(text: &'a str) -> Self {
+        Self { text, offset: 0 }
+    }
+
+    fn error(&self, msg: impl Into<String>) -> ParseError {
+        ParseError {
+            msg: msg.into(),
+            offset: self.offset,
+            len: 0,
+            help: None,
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.text[self.offset..].chars().next()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(c) = self.peek() {
+            if !c.is_whitespace() {
+                break;
+            }
+            self.offset += c.len_utf8();
+        }
+    }
+
+    /// Consume `expected` if it is the next non-whitespace character.
+    fn eat(&mut self, expected: char) -> bool {
+        self.skip_whitespace();
+        if self.peek() == Some(expected) {
+            self.offset += expected.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), ParseError> {
+        if self.eat(expected) {
+            Ok(())
+        } else {
+            match self.peek() {
+                Some(c) => Err(self.error(format!("expected '{expected}', found '{c}'"))),
+                None => Err(self.error(format!("expected '{expected}', found end of pattern"))),
+            }
+        }
+    }
+
+    fn parse_ident(&mut self) -> Result<&'a str, ParseError> {
+        self.skip_whitespace();
+        let start = self.offset;
+        while let Some(c) = self.peek() {
+            if !(c.is_alphanumeric() || c == '_' || c == '-') {
+                break;
+            }
+            self.offset += c.len_utf8();
+        }
+        if start == self.offset {
+            match self.peek() {
+                Some(c) => Err(self.error(format!("expected a name, found '{c}'"))),
+                None => Err(self.error("expected a name, found end of pattern")),
+            }
+        } else {
+            Ok(&self.text[start..self.offset])
+        }
+    }
+
+    /// Field names are identifiers or quoted strings, e.g. `record<"first name": string>`.
+    fn parse_field_name(&mut self) -> Result<String, ParseError> {
+        self.skip_whitespace();
+        match self.peek() {
+            Some(quote @ ('"' | '\'' | '`')) => {
+                let start = self.offset;
+                self.offset += 1;
+                match self.text[self.offset..].find(quote) {
+                    Some(len) => {
+                        let name = self.text[self.offset..self.offset + len].to_string();
+                        self.offset += len + 1;
+                        Ok(name)
+                    }
+                    None => Err(ParseError {
+                        msg: "unclosed quote in field name".into(),
+                        offset: start,
+                        len: 1,
+                        help: None,
+                    }),
+                }
+            }
+            _ => self.parse_ident().map(String::from),
+        }
+    }
+
+    /// Parse a union of patterns, the lowest precedence level.
+    fn parse_pattern(&mut self) -> Result<TypePattern, ParseError> {
+        let mut patterns = vec![self.parse_unary()?];
+        while self.eat('|') {
+            patterns.push(self.parse_unary()?);
+        }
+        if patterns.len() == 1 {
+            Ok(patterns.pop().unwrap())
+        } else {
+            Ok(TypePattern::OneOf(patterns))
+        }
+    }
+
+    fn parse_unary(&mut self) -> Result<TypePattern, ParseError> {
+        if self.eat('!') {
+            return Ok(TypePattern::Not(Box::new(self.parse_unary()?)));
+        }
+        if self.eat('(') {
+            let pattern = self.parse_pattern()?;
+            self.expect(')')?;
+            return Ok(pattern);
+        }
+        self.parse_named()
+    }
+
+    fn parse_named(&mut self) -> Result<TypePattern, ParseError> {
+        let name = self.parse_ident()?;
+        let start = self.offset - name.len();
+        let pattern = match name {
+            "any" => TypePattern::Any,
+            "binary" => TypePattern::Binary,
+            "block" => TypePattern::Block,
+            "bool" => TypePattern::Bool,
+            "cell-path" => TypePattern::CellPath,
+            "closure" => TypePattern::Closure,
+            "date" | "datetime" => TypePattern::Date,
+            "duration" => TypePattern::Duration,
+            "error" => TypePattern::Error,
+            "filesize" => TypePattern::Filesize,
+            "float" => TypePattern::Float,
+            "glob" => TypePattern::Glob,
+            "int" => TypePattern::Int,
+            "nothing" => TypePattern::Nothing,
+            "number" => TypePattern::Number,
+            "range" => TypePattern::Range,
+            "string" => TypePattern::String,
+            "list" => {
+                if self.eat('<') {
+                    let inner = self.parse_pattern()?;
+                    self.expect('>')?;
+                    TypePattern::List(Box::new(inner))
+                } else {
+                    TypePattern::List(Box::new(TypePattern::Any))
+                }
+            }
+            "oneof" => {
+                self.expect('<')?;
+                let mut patterns = vec![self.parse_pattern()?];
+                while self.eat(',') {
+                    patterns.push(self.parse_pattern()?);
+                }
+                self.expect('>')?;
+                TypePattern::OneOf(patterns)
+            }
+            "record" => TypePattern::Record(self.parse_fields()?),
+            "table" => TypePattern::Table(self.parse_fields()?),
+            "custom" => {
+                self.expect('<')?;
+                let name = self.parse_ident()?;
+                self.expect('>')?;
+                TypePattern::Custom(name.to_string())
+            }
+            other => {
+                let help = match did_you_mean(&TYPE_NAMES, other) {
+                    Some(suggestion) => format!("did you mean `{suggestion}`?"),
+                    None => {
+                        format!("use `custom<{other}>` for plugin custom values named `{other}`")
+                    }
+                };
+                return Err(ParseError {
+                    msg: format!("unknown type `{other}`"),
+                    offset: start,
+                    len: other.len(),
+                    help: Some(help),
+                });
+            }
+        };
+        Ok(pattern)
+    }
+
+    /// Parse the optional `<name: type, name, ...>` part of record and table.
+    fn parse_fields(&mut self) -> Result<Vec<FieldPattern>, ParseError> {
+        let mut fields = vec![];
+        if !self.eat('<') {
+            return Ok(fields);
+        }
+        loop {
+            if self.eat('>') {
+                break;
+            }
+            let name = self.parse_field_name()?;
+            let ty = if self.eat(':') {
+                Some(self.parse_pattern()?)
+            } else {
+                None
+            };
+            fields.push(FieldPattern { name, ty });
+            if !self.eat(',') {
+                self.expect('>')?;
+                break;
+            }
+        }
+        Ok(fields)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nu_protocol::{Value, record};
+
+    use super::*;
+
+    fn parse(text: &str) -> TypePattern {
+        TypePattern::parse(text).unwrap()
+    }
+
+    fn field(name: &str, ty: Option<TypePattern>) -> FieldPattern {
+        FieldPattern {
+            name: name.into(),
+            ty,
+        }
+    }
+
+    #[test]
+    fn parse_simple_names() {
+        assert_eq!(parse("int"), TypePattern::Int);
+        assert_eq!(parse("date"), TypePattern::Date);
+        assert_eq!(parse("datetime"), TypePattern::Date);
+        assert_eq!(parse("cell-path"), TypePattern::CellPath);
+        assert_eq!(parse("list"), TypePattern::List(Box::new(TypePattern::Any)));
+        assert_eq!(parse("record"), TypePattern::Record(vec![]));
+    }
+
+    #[test]
+    fn parse_ignores_whitespace() {
+        assert_eq!(
+            parse("record<name:string,rank:int>"),
+            parse("  record < name : string , rank : int >  "),
+        );
+        assert_eq!(parse(" list< int > "), parse("list<int>"));
+        assert_eq!(parse("int|float"), parse("int | float"));
+    }
+
+    #[test]
+    fn parse_quoted_field_names() {
+        assert_eq!(
+            parse(r#"record<"first name": string, 'last name', `a|b`: int>"#),
+            TypePattern::Record(vec![
+                field("first name", Some(TypePattern::String)),
+                field("last name", None),
+                field("a|b", Some(TypePattern::Int)),
+            ]),
+        );
+    }
+
+    #[test]
+    fn parse_nested() {
+        assert_eq!(
+            parse("list<record<tags: list<string>, meta: table<id: int>>>"),
+            TypePattern::List(Box::new(TypePattern::Record(vec![
+                field(
+                    "tags",
+                    Some(TypePattern::List(Box::new(TypePattern::String)))
+                ),
+                field(
+                    "meta",
+                    Some(TypePattern::Table(vec![field(
+                        "id",
+                        Some(TypePattern::Int)
+                    )]))
+                ),
+            ]))),
+        );
+        assert_eq!(
+            parse("oneof<int, list<oneof<string, nothing>>>"),
+            TypePattern::OneOf(vec![
+                TypePattern::Int,
+                TypePattern::List(Box::new(TypePattern::OneOf(vec![
+                    TypePattern::String,
+                    TypePattern::Nothing,
+                ]))),
+            ]),
+        );
+    }
+
+    #[test]
+    fn negation_binds_tighter_than_union() {
+        assert_eq!(
+            parse("!int | string"),
+            TypePattern::OneOf(vec![
+                TypePattern::Not(Box::new(TypePattern::Int)),
+                TypePattern::String,
+            ]),
+        );
+        assert_eq!(
+            parse("!(int | string)"),
+            TypePattern::Not(Box::new(TypePattern::OneOf(vec![
+                TypePattern::Int,
+                TypePattern::String,
+            ]))),
+        );
+        assert_eq!(
+            parse("!!nothing"),
+            TypePattern::Not(Box::new(TypePattern::Not(Box::new(TypePattern::Nothing)))),
+        );
+    }
+
+    #[test]
+    fn parse_error_offsets() {
+        let error = |text| TypePattern::parse(text).unwrap_err();
+
+        assert_eq!(error("").offset, 0);
+        assert_eq!(error("list<int").offset, 8);
+        assert_eq!(error("list<int>>").offset, 9);
+        assert_eq!(error("record<name: >").offset, 13);
+        assert_eq!(error("(int | float").offset, 12);
+        assert_eq!(error("record<\"name: int>").offset, 7);
+        assert_eq!(
+            error("int string"),
+            ParseError {
+                msg: "unexpected character 's'".into(),
+                offset: 4,
+                len: 0,
+                help: None,
+            }
+        );
+    }
+
+    #[test]
+    fn unknown_names_are_errors() {
+        let error = TypePattern::parse("list<strnig>").unwrap_err();
+        assert_eq!(error.msg, "unknown type `strnig`");
+        assert_eq!((error.offset, error.len), (5, 6));
+        assert_eq!(error.help.as_deref(), Some("did you mean `string`?"));
+
+        let error = TypePattern::parse("function").unwrap_err();
+        assert_eq!(
+            error.help.as_deref(),
+            Some("use `custom<function>` for plugin custom values named `function`")
+        );
+    }
+
+    #[test]
+    fn parse_custom() {
+        assert_eq!(
+            parse("custom<function> | nothing"),
+            TypePattern::OneOf(vec![
+                TypePattern::Custom("function".into()),
+                TypePattern::Nothing,
+            ]),
+        );
+    }
+
+    #[test]
+    fn display_round_trips() {
+        for text in [
+            "int",
+            "datetime",
+            "cell-path",
+            "list<string>",
+            "record<name: string, rank>",
+            r#"record<"first name": string>"#,
+            "table<id: int, tags: list<string>>",
+            "int | float | nothing",
+            "!nothing",
+            "!(int | float)",
+            "list<!int | string>",
+            "custom<maybe>",
+            "record<a: record<b: list<int | nothing>>>",
+        ] {
+            let pattern = parse(text);
+            assert_eq!(pattern.to_string(), text);
+            assert_eq!(parse(&pattern.to_string()), pattern);
+        }
+    }
+
+    #[test]
+    fn matches_values() {
+        let value = Value::test_record(record! {
+            "name" => Value::test_string("Alice"),
+            "rank" => Value::test_int(10),
+        });
+
+        assert!(parse("record<rank: int, name: string>").matches(&value));
+        assert!(parse("record<name>").matches(&value));
+        assert!(!parse("record<age>").matches(&value));
+        assert!(parse("number").matches(&Value::test_float(1.5)));
+        assert!(parse("!nothing").matches(&Value::test_int(1)));
+        assert!(parse("table<name: string>").matches(&Value::test_list(vec![value.clone()])));
+        assert!(!parse("list<int>").matches(&Value::test_list(vec![
+            Value::test_int(1),
+            Value::test_string("a"),
+        ])));
+    }
+
+    #[test]
+    fn mismatches_report_paths() {
+        let value = Value::test_list(vec![
+            Value::test_record(record! { "id" => Value::test_int(1) }),
+            Value::test_record(record! { "id" => Value::test_string("2") }),
+            Value::test_record(record! {}),
+        ]);
+        let pattern = parse("table<id: int>");
+
+        let first = pattern.mismatches(&value, false);
+        assert_eq!(first.len(), 1);
+        assert_eq!(first[0].expected, "int");
+        assert_eq!(first[0].actual, "string");
+        assert_eq!(
+            first[0].path,
+            vec![
+                PathMember::int(1, false, Span::test_data()),
+                PathMember::string("id".into(), false, Casing::Sensitive, Span::test_data()),
+            ]
+        );
+
+        let all = pattern.mismatches(&value, true);
+        assert_eq!(all.len(), 2);
+        assert_eq!(all[1].actual, "missing");
+        assert!(
+            pattern
+                .mismatches(&Value::test_list(vec![]), true)
+                .is_empty()
+        );
+    }
+}
@@ -0,0 +1,47 @@
+use nu_protocol::{CustomValue, ShellError, Span, Value};
+use serde::{Deserialize, Serialize};
+
+/// Marker returned by `fp break` to stop an iteration early with a value.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct BreakValue {
+    pub value: Value,
+}
+
+impl BreakValue {
+    pub fn into_value(self, span: Span) -> Value {
+        Value::custom(Box::new(self), span)
+    }
+
+    /// Get the value to break with if `value` is a break marker.
+    pub fn try_from_value(value: &Value) -> Option<&Value> {
+        match value {
+            Value::Custom { val, .. } => {
+                val.as_any().downcast_ref::<BreakValue>().map(|b| &b.value)
+            }
+            _ => None,
+        }
+    }
+}
+
+#[typetag::serde]
+impl CustomValue for BreakValue {
+    fn clone_value(&self, span: Span) -> Value {
+        self.clone().into_value(span)
+    }
+
+    fn type_name(&self) -> String {
+        "break".into()
+    }
+
+    fn to_base_value(&self, _span: Span) -> Result<Value, ShellError> {
+        Ok(self.value.clone())
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_mut_any(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
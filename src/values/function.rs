@@ -0,0 +1,148 @@
+use nu_plugin::EngineInterface;
+use nu_protocol::{CustomValue, LabeledError, ShellError, Span, Spanned, Value, engine::Closure};
+use serde::{Deserialize, Serialize};
+
+use crate::FpPlugin;
+
+/// A callable value built from closures by commands like `fp compose`, invoked by `fp call`.
+///
+/// Plugins can not create nushell closures, so functions built by the plugin are custom values
+/// evaluated by the plugin.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum FunctionValue {
+    /// A nushell closure.
+    Closure(Spanned<Closure>),
+
+    /// Functions called from left to right, each one is called with the output of the previous
+    /// one.
+    Pipe(Vec<FunctionValue>),
+
+    /// A function with leading arguments bound, the arguments of a call follow the bound ones.
+    Partial {
+        function: Box<FunctionValue>,
+        args: Vec<Value>,
+    },
+
+    /// A function whose results are cached in the plugin by arguments.
+    Memoized {
+        id: u64,
+        function: Box<FunctionValue>,
+        /// Maximum number of cached results, read from the config by `fp memoize`.
+        max_entries: Option<usize>,
+    },
+}
+
+impl FunctionValue {
+    pub fn into_value(self, span: Span) -> Value {
+        Value::custom(Box::new(self), span)
+    }
+
+    /// Get the function if `value` is a function value.
+    pub fn try_from_value(value: &Value) -> Option<&FunctionValue> {
+        match value {
+            Value::Custom { val, .. } => val.as_any().downcast_ref::<FunctionValue>(),
+            _ => None,
+        }
+    }
+
+    /// Check if `value` is a closure or a function value.
+    pub fn is_callable(value: &Value) -> bool {
+        matches!(value, Value::Closure { .. }) || Self::try_from_value(value).is_some()
+    }
+
+    /// Convert a closure or a function value into function.
+    pub fn from_callable(value: Value) -> Result<Self, LabeledError> {
+        let span = value.span();
+        if let Some(function) = Self::try_from_value(&value) {
+            return Ok(function.clone());
+        }
+        match value {
+            Value::Closure { val, .. } => Ok(FunctionValue::Closure(Spanned { item: *val, span })),
+            value => Err(LabeledError::new("Not a function").with_label(
+                format!("expected a closure or function, found {}", value.get_type()),
+                span,
+            )),
+        }
+    }
+
+    /// Call the function with positional arguments and pipeline input.
+    pub fn call(
+        &self,
+        plugin: &FpPlugin,
+        engine: &EngineInterface,
+        args: Vec<Value>,
+        input: Option<Value>,
+    ) -> Result<Value, LabeledError> {
+        match self {
+            FunctionValue::Closure(closure) => Ok(engine.eval_closure(closure, args, input)?),
+            FunctionValue::Pipe(functions) => {
+                let Some((first, rest)) = functions.split_first() else {
+                    // An empty pipe is the identity function.
+                    return Ok(args
+                        .into_iter()
+                        .next()
+                        .or(input)
+                        .unwrap_or(Value::nothing(Span::unknown())));
+                };
+                let mut value = first.call(plugin, engine, args, input)?;
+                for function in rest {
+                    value = function.call(plugin, engine, vec![value.clone()], Some(value))?;
+                }
+                Ok(value)
+            }
+            FunctionValue::Partial {
+                function,
+                args: bound,
+            } => {
+                let args = bound.iter().cloned().chain(args).collect();
+                function.call(plugin, engine, args, input)
+            }
+            FunctionValue::Memoized {
+                id,
+                function,
+                max_entries,
+            } => {
+                // Results are keyed by the arguments only, so `fp call $f 5` and `5 | fp call $f`
+                // share the cached result.
+                if let Some(result) = plugin.memo().get(*id, &args) {
+                    return Ok(result);
+                }
+                let key = args.clone();
+                let result = function.call(plugin, engine, args, input)?;
+                plugin.memo().insert(*id, key, result.clone(), *max_entries);
+                Ok(result)
+            }
+        }
+    }
+}
+
+#[typetag::serde]
+impl CustomValue for FunctionValue {
+    fn clone_value(&self, span: Span) -> Value {
+        self.clone().into_value(span)
+    }
+
+    fn type_name(&self) -> String {
+        "function".into()
+    }
+
+    fn to_base_value(&self, span: Span) -> Result<Value, ShellError> {
+        let description = match self {
+            FunctionValue::Closure(_) => "<function>".to_string(),
+            FunctionValue::Pipe(functions) => format!("<function: pipe of {}>", functions.len()),
+            FunctionValue::Partial { args, .. } => {
+                format!("<function: partial with {} bound>", args.len())
+            }
+            FunctionValue::Memoized { .. } => "<function: memoized>".to_string(),
+        };
+        Ok(Value::string(description, span))
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_mut_any(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
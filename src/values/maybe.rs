@@ -0,0 +1,69 @@
+use nu_protocol::{CustomValue, LabeledError, ShellError, Span, Value};
+use serde::{Deserialize, Serialize};
+
+/// An optional value returned by `fp some` and `fp none`.
+///
+/// Unlike `null`, `some null` is present, so "nothing found" is not confused with "found `null`".
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum MaybeValue {
+    Some(Value),
+    None,
+}
+
+impl MaybeValue {
+    pub fn into_value(self, span: Span) -> Value {
+        Value::custom(Box::new(self), span)
+    }
+
+    /// Get the maybe value if `value` is one.
+    pub fn try_from_value(value: &Value) -> Option<&MaybeValue> {
+        match value {
+            Value::Custom { val, .. } => val.as_any().downcast_ref::<MaybeValue>(),
+            _ => None,
+        }
+    }
+
+    /// Get the maybe value, or an error if `value` is not one.
+    pub fn from_value(value: &Value) -> Result<&MaybeValue, LabeledError> {
+        Self::try_from_value(value).ok_or_else(|| {
+            LabeledError::new("Not a maybe value").with_label(
+                format!(
+                    "expected a value from `fp some` or `fp none`, found {}",
+                    value.get_type()
+                ),
+                value.span(),
+            )
+        })
+    }
+
+    /// Convert to a plain value, `none` becomes `null`.
+    pub fn into_nullable(self, span: Span) -> Value {
+        match self {
+            MaybeValue::Some(value) => value,
+            MaybeValue::None => Value::nothing(span),
+        }
+    }
+}
+
+#[typetag::serde]
+impl CustomValue for MaybeValue {
+    fn clone_value(&self, span: Span) -> Value {
+        self.clone().into_value(span)
+    }
+
+    fn type_name(&self) -> String {
+        "maybe".into()
+    }
+
+    fn to_base_value(&self, span: Span) -> Result<Value, ShellError> {
+        Ok(self.clone().into_nullable(span))
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_mut_any(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
@@ -0,0 +1,11 @@
+//! Custom values provided by the plugin.
+
+mod break_;
+mod function;
+mod maybe;
+mod result;
+
+pub use break_::BreakValue;
+pub use function::FunctionValue;
+pub use maybe::MaybeValue;
+pub use result::{ResultValue, value_to_error};
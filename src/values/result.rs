@@ -0,0 +1,193 @@
+use nu_protocol::{CustomValue, LabeledError, ShellError, Span, Value, record};
+use serde::{Deserialize, Serialize};
+
+/// The outcome of a closure returned by `fp try`, either `ok` with the result or `err` with the
+/// error.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub enum ResultValue {
+    Ok(Value),
+    Err(Value),
+}
+
+impl ResultValue {
+    pub fn into_value(self, span: Span) -> Value {
+        Value::custom(Box::new(self), span)
+    }
+
+    /// Get the result value if `value` is one.
+    pub fn try_from_value(value: &Value) -> Option<&ResultValue> {
+        match value {
+            Value::Custom { val, .. } => val.as_any().downcast_ref::<ResultValue>(),
+            _ => None,
+        }
+    }
+
+    /// Get the result value, or an error if `value` is not one.
+    pub fn from_value(value: &Value) -> Result<&ResultValue, LabeledError> {
+        Self::try_from_value(value).ok_or_else(|| {
+            LabeledError::new("Not a result value").with_label(
+                format!("expected a value from `fp try`, found {}", value.get_type()),
+                value.span(),
+            )
+        })
+    }
+
+    /// Capture the outcome of a closure call, errors become `err` of a record of
+    /// `{msg, labels: [{text, span}], help, span}`.
+    pub fn from_outcome(outcome: Result<Value, LabeledError>, span: Span) -> Self {
+        match outcome {
+            Ok(Value::Error { error, .. }) => {
+                ResultValue::Err(error_to_value(LabeledError::from(*error), span))
+            }
+            Ok(value) => ResultValue::Ok(value),
+            Err(error) => ResultValue::Err(error_to_value(error, span)),
+        }
+    }
+}
+
+#[typetag::serde]
+impl CustomValue for ResultValue {
+    fn clone_value(&self, span: Span) -> Value {
+        self.clone().into_value(span)
+    }
+
+    fn type_name(&self) -> String {
+        "result".into()
+    }
+
+    fn to_base_value(&self, _span: Span) -> Result<Value, ShellError> {
+        match self {
+            ResultValue::Ok(value) | ResultValue::Err(value) => Ok(value.clone()),
+        }
+    }
+
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_mut_any(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+}
+
+/// Convert an error to a record, `span` is used if the error has no label.
+fn error_to_value(error: LabeledError, span: Span) -> Value {
+    let error_span = error.labels.first().map_or(span, |label| label.span);
+    let labels = error
+        .labels
+        .iter()
+        .map(|label| {
+            Value::record(
+                record! {
+                    "text" => Value::string(label.text.clone(), span),
+                    "span" => span_to_value(label.span, span),
+                },
+                span,
+            )
+        })
+        .collect();
+
+    Value::record(
+        record! {
+            "msg" => Value::string(error.msg, span),
+            "labels" => Value::list(labels, span),
+            "help" => error.help.map_or(Value::nothing(span), |help| Value::string(help, span)),
+            "span" => span_to_value(error_span, span),
+        },
+        span,
+    )
+}
+
+fn span_to_value(error_span: Span, span: Span) -> Value {
+    Value::record(
+        record! {
+            "start" => Value::int(error_span.start as i64, span),
+            "end" => Value::int(error_span.end as i64, span),
+        },
+        span,
+    )
+}
+
+/// Convert the error of `err` back to an error.
+///
+/// Records from `fp try` (possibly changed by `fp map-err`) keep the message, labels and help,
+/// other values are reported as they are.
+pub fn value_to_error(value: &Value, span: Span) -> LabeledError {
+    let Ok(record) = value.as_record() else {
+        return LabeledError::new("Unwrapped an error result").with_label(
+            format!(
+                "error: {}",
+                value.to_abbreviated_string(&Default::default())
+            ),
+            span,
+        );
+    };
+
+    let msg = record
+        .get("msg")
+        .and_then(|msg| msg.coerce_string().ok())
+        .unwrap_or_else(|| "Unwrapped an error result".into());
+    let mut error = LabeledError::new(msg);
+    let labels = record
+        .get("labels")
+        .and_then(|labels| labels.as_list().ok())
+        .unwrap_or_default();
+    for label in labels {
+        let text = label
+            .get_data_by_key("text")
+            .and_then(|text| text.coerce_string().ok())
+            .unwrap_or_default();
+        let label_span = label
+            .get_data_by_key("span")
+            .and_then(|label_span| value_to_span(&label_span))
+            .unwrap_or(span);
+        error = error.with_label(text, label_span);
+    }
+    if labels.is_empty() {
+        error = error.with_label("error result unwrapped here", span);
+    }
+    if let Some(help) = record
+        .get("help")
+        .and_then(|help| help.coerce_string().ok())
+    {
+        error = error.with_help(help);
+    }
+    error
+}
+
+/// Read a span from a `{start, end}` record, `None` if the bounds are not a valid span.
+fn value_to_span(value: &Value) -> Option<Span> {
+    let start = value.get_data_by_key("start")?.as_int().ok()?;
+    let end = value.get_data_by_key("end")?.as_int().ok()?;
+    let start = usize::try_from(start).ok()?;
+    let end = usize::try_from(end).ok()?;
+    (start <= end).then(|| Span::new(start, end))
+}
+
+#[cfg(test)]
+mod tests {
+    use nu_protocol::{Span, Value, record};
+
+    use super::value_to_span;
+
+    fn span_record(start: i64, end: i64) -> Value {
+        Value::test_record(record! {
+            "start" => Value::test_int(start),
+            "end" => Value::test_int(end),
+        })
+    }
+
+    #[test]
+    fn valid_spans() {
+        assert_eq!(value_to_span(&span_record(2, 5)), Some(Span::new(2, 5)));
+        assert_eq!(value_to_span(&span_record(3, 3)), Some(Span::new(3, 3)));
+    }
+
+    #[test]
+    fn invalid_spans() {
+        assert_eq!(value_to_span(&span_record(-1, 5)), None);
+        assert_eq!(value_to_span(&span_record(2, -5)), None);
+        assert_eq!(value_to_span(&span_record(5, 2)), None);
+        assert_eq!(value_to_span(&Value::test_int(5)), None);
+    }
+}
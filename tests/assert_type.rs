@@ -0,0 +1,19 @@
+use nu_plugin_functional::FpPlugin;
+use nu_plugin_test_support::PluginTest;
+use nu_protocol::{LabeledError, ShellError};
+
+#[test]
+fn mismatch_label_has_path() -> Result<(), LabeledError> {
+    let error = PluginTest::new("functional", FpPlugin::default().into())?
+        .eval(r#"[{name: "Alice", rank: "10"}] | fp assert-type 'table<rank: int>'"#)
+        .expect_err("the input does not match");
+
+    let ShellError::LabeledError(error) = error else {
+        panic!("expected a labeled error, found {error:?}");
+    };
+    assert_eq!(
+        error.labels[0].text,
+        "expected int, found string at `0.rank`"
+    );
+    Ok(())
+}
@@ -0,0 +1,97 @@
+use nu_plugin::Plugin;
+use nu_plugin_functional::FpPlugin;
+use nu_plugin_test_support::PluginTest;
+use nu_protocol::{LabeledError, engine::EngineState};
+
+/// Names at command positions in `source`, i.e. at the start of a pipeline element or a block.
+///
+/// Strings and closure parameters are skipped, it is good enough for the examples.
+fn command_words(source: &str) -> Vec<Vec<&str>> {
+    let mut commands = vec![];
+    let mut command_position = true;
+    let mut chars = source.char_indices().peekable();
+    while let Some((start, c)) = chars.next() {
+        match c {
+            '"' | '\'' | '`' => {
+                let mut escaped = false;
+                for (_, next) in chars.by_ref() {
+                    if next == c && !escaped {
+                        break;
+                    }
+                    escaped = c == '"' && next == '\\' && !escaped;
+                }
+                command_position = false;
+            }
+            '{' => {
+                while chars.next_if(|(_, c)| c.is_whitespace()).is_some() {}
+                if chars.next_if(|(_, c)| *c == '|').is_some() {
+                    for (_, next) in chars.by_ref() {
+                        if next == '|' {
+                            break;
+                        }
+                    }
+                }
+                command_position = true;
+            }
+            '|' | ';' | '(' => command_position = true,
+            c if c.is_whitespace() => {}
+            _ => {
+                let mut end = start + c.len_utf8();
+                while let Some((index, next)) =
+                    chars.next_if(|(_, c)| !c.is_whitespace() && !"|;(){}[]".contains(*c))
+                {
+                    end = index + next.len_utf8();
+                }
+                if command_position {
+                    let mut words = vec![&source[start..end]];
+                    // Keep the next word, for subcommands like `fp call`.
+                    let rest = source[end..].trim_start();
+                    if let Some(word) = rest
+                        .split(|c: char| !c.is_alphanumeric() && c != '-')
+                        .next()
+                    {
+                        words.push(word);
+                    }
+                    commands.push(words);
+                }
+                command_position = false;
+            }
+        }
+    }
+    commands
+}
+
+/// Check if all commands used in `source` are available, the test engine only has the core
+/// language commands and commands of this plugin.
+fn commands_available(engine_state: &EngineState, source: &str) -> bool {
+    command_words(source).iter().all(|words| {
+        let name = words[0];
+        let is_command_name = name.starts_with(|c: char| c.is_ascii_lowercase())
+            && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-');
+        if !is_command_name || ["null", "true", "false"].contains(&name) {
+            return true;
+        }
+        engine_state.find_decl(name.as_bytes(), &[]).is_some()
+            || engine_state
+                .find_decl(words.join(" ").as_bytes(), &[])
+                .is_some()
+    })
+}
+
+#[test]
+fn examples() -> Result<(), LabeledError> {
+    let mut failed = vec![];
+    for command in FpPlugin::default().commands() {
+        let mut test = PluginTest::new("functional", FpPlugin::default().into())?;
+        let examples = command
+            .examples()
+            .into_iter()
+            .filter(|example| commands_available(test.engine_state(), example.example))
+            .collect::<Vec<_>>();
+        if test.test_examples(&examples).is_err() {
+            failed.push(command.name().to_string());
+        }
+    }
+    assert!(failed.is_empty(), "examples failed: {}", failed.join(", "));
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use std::sync::{
+    Arc,
+    atomic::{AtomicUsize, Ordering},
+};
+
+use nu_plugin_functional::FpPlugin;
+use nu_plugin_test_support::PluginTest;
+use nu_protocol::{LabeledError, ListStream, PipelineData, Signals, Span, Value};
+
+#[test]
+fn first_where_stops_infinite_stream() -> Result<(), LabeledError> {
+    let produced = Arc::new(AtomicUsize::new(0));
+    let counter = produced.clone();
+    let stream = (1..).map(move |i| {
+        counter.fetch_add(1, Ordering::Relaxed);
+        Value::test_int(i)
+    });
+    let input = PipelineData::ListStream(
+        ListStream::new(stream, Span::test_data(), Signals::empty()),
+        None,
+    );
+
+    let result = PluginTest::new("functional", FpPlugin::default().into())?
+        .eval_with("fp first-where {|x| $x > 1000}", input)?
+        .into_value(Span::test_data())?;
+
+    assert_eq!(result, Value::test_int(1001));
+    // The stream is dropped after the match, only elements already buffered between the engine
+    // and the plugin are produced past it.
+    assert!(produced.load(Ordering::Relaxed) < 2000);
+    Ok(())
+}
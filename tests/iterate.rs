@@ -0,0 +1,30 @@
+use nu_plugin_functional::FpPlugin;
+use nu_plugin_test_support::PluginTest;
+use nu_protocol::{LabeledError, Span, Value};
+
+#[test]
+fn iterate_calls_closure_only_for_pulled_elements() -> Result<(), LabeledError> {
+    // Calling the closure on 3 fails, which is never needed to find 3.
+    let result = PluginTest::new("functional", FpPlugin::default().into())?
+        .eval("fp iterate 1 {|x| if $x == 3 { null | error make {msg: boom} } else { $x + 1 }} | fp first-where $it == 3")?
+        .into_value(Span::test_data())?;
+
+    assert_eq!(result, Value::test_int(3));
+    Ok(())
+}
+
+#[test]
+fn iterate_yields_elements_before_error() -> Result<(), LabeledError> {
+    let values = PluginTest::new("functional", FpPlugin::default().into())?
+        .eval("fp iterate 1 {|x| if $x == 2 { null | error make {msg: boom} } else { $x + 1 }}")?
+        .into_iter()
+        .collect::<Vec<_>>();
+
+    assert_eq!(values.len(), 3);
+    assert_eq!(values[..2], [Value::test_int(1), Value::test_int(2)]);
+    let Value::Error { error, .. } = &values[2] else {
+        panic!("expected an error, found {:?}", values[2]);
+    };
+    assert!(error.to_string().contains("boom"));
+    Ok(())
+}
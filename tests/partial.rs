@@ -0,0 +1,36 @@
+use nu_plugin_functional::FpPlugin;
+use nu_plugin_test_support::PluginTest;
+use nu_protocol::{LabeledError, Span, Value, record};
+
+fn eval(source: &str) -> Result<Value, LabeledError> {
+    Ok(PluginTest::new("functional", FpPlugin::default().into())?
+        .eval(source)?
+        .into_value(Span::test_data())?)
+}
+
+#[test]
+fn first_where_partial() -> Result<(), LabeledError> {
+    let result = eval("let f = fp partial {|n, x| $x > $n} 5; [1, 2, 4, 8] | fp first-where $f")?;
+
+    assert_eq!(result, Value::test_int(8));
+    Ok(())
+}
+
+#[test]
+fn row_condition_commands_accept_functions() -> Result<(), LabeledError> {
+    let taken = eval("let f = fp partial {|n, x| $x < $n} 5; [1, 2, 8, 4] | fp take-while $f")?;
+    let parts = eval("let f = fp partial {|n, x| $x < $n} 5; [1, 8, 4] | fp partition $f")?;
+
+    assert_eq!(
+        taken,
+        Value::test_list(vec![Value::test_int(1), Value::test_int(2)])
+    );
+    assert_eq!(
+        parts,
+        Value::test_record(record! {
+            "pass" => Value::test_list(vec![Value::test_int(1), Value::test_int(4)]),
+            "fail" => Value::test_list(vec![Value::test_int(8)]),
+        })
+    );
+    Ok(())
+}
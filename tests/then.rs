@@ -0,0 +1,61 @@
+use nu_plugin_functional::FpPlugin;
+use nu_plugin_test_support::PluginTest;
+use nu_protocol::{LabeledError, ListStream, PipelineData, ShellError, Signals, Span, Value};
+
+#[test]
+fn each_keeps_scalar_input() -> Result<(), LabeledError> {
+    let mut plugin = PluginTest::new("functional", FpPlugin::default().into())?;
+    let then = plugin
+        .eval("1 | fp then --each { $in + 2 }")?
+        .into_value(Span::test_data())?;
+    let other = plugin
+        .eval("null | fp other --each 0")?
+        .into_value(Span::test_data())?;
+
+    assert_eq!(then, Value::test_int(3));
+    assert_eq!(other, Value::test_int(0));
+    Ok(())
+}
+
+#[test]
+fn each_passes_error_elements_through() -> Result<(), LabeledError> {
+    let error = || {
+        Value::error(
+            ShellError::GenericError {
+                error: "boom".into(),
+                msg: "upstream".into(),
+                span: None,
+                help: None,
+                inner: vec![],
+            },
+            Span::test_data(),
+        )
+    };
+    let input = || {
+        let elements = vec![Value::test_int(1), error(), Value::test_nothing()];
+        PipelineData::ListStream(
+            ListStream::new(elements.into_iter(), Span::test_data(), Signals::empty()),
+            None,
+        )
+    };
+    let mut plugin = PluginTest::new("functional", FpPlugin::default().into())?;
+
+    let then = plugin
+        .eval_with("fp then --each {|x| $x + 2 }", input())?
+        .into_iter()
+        .collect::<Vec<_>>();
+    let other = plugin
+        .eval_with("fp other --each {|x| 0 }", input())?
+        .into_iter()
+        .collect::<Vec<_>>();
+
+    assert_eq!(then.len(), 3);
+    assert_eq!(then[0], Value::test_int(3));
+    assert!(matches!(&then[1], Value::Error { error, .. } if error.to_string() == "boom"));
+    assert_eq!(then[2], Value::test_nothing());
+    assert_eq!(other.len(), 3);
+    assert_eq!(other[0], Value::test_int(1));
+    assert!(matches!(&other[1], Value::Error { error, .. } if error.to_string() == "boom"));
+    assert_eq!(other[2], Value::test_int(0));
+    Ok(())
+}